@@ -0,0 +1,354 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::iter;
+use std::path::{Path, PathBuf};
+use crate::image::{Image, ImageFormat, Rgb};
+use crate::render::Canvas;
+
+/// GIF images index a palette of at most 256 colors.
+const MAX_COLORS: usize = 256;
+
+/// LZW codes are at most 12 bits.
+const MAX_CODES: usize = 4096;
+
+/// Captures the canvas of every step of a simulation, to write them as numbered images or as an animated GIF. Frames
+/// only keep the palette index of every cell. Once the palette holds 256 colors, new colors are drawn in the nearest
+/// color of the palette.
+#[derive(Clone, Debug, Default)]
+pub struct Recorder {
+    palette: Vec<Rgb>,
+    indices: HashMap<Rgb, u8>,
+    frames: Vec<Vec<u8>>,
+    height: usize,
+    width: usize,
+}
+
+impl Recorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the canvas as the next frame, it must have the size of the frames before it.
+    pub fn record(&mut self, canvas: &Canvas) {
+        let cells = canvas.cells();
+
+        if self.frames.is_empty() {
+            (self.height, self.width) = (cells.height(), cells.width());
+        }
+
+        assert!(
+            (cells.height(), cells.width()) == (self.height, self.width),
+            "a canvas of {}x{} can not be recorded with frames of {}x{}", cells.height(), cells.width(), self.height, self.width,
+        );
+
+        let frame = cells.iter().map(|(_, cell)| self.index(cell.color)).collect();
+        self.frames.push(frame);
+    }
+
+    fn index(&mut self, color: Rgb) -> u8 {
+        if let Some(&index) = self.indices.get(&color) {
+            return index;
+        }
+
+        let index = if self.palette.len() < MAX_COLORS {
+            self.palette.push(color);
+            self.palette.len() - 1
+        } else {
+            let distance = |other: &Rgb| {
+                [(color.r, other.r), (color.g, other.g), (color.b, other.b)]
+                    .into_iter()
+                    .map(|(a, b)| (a as i32 - b as i32).pow(2))
+                    .sum::<i32>()
+            };
+
+            (0..self.palette.len()).min_by_key(|&i| distance(&self.palette[i])).unwrap()
+        };
+
+        self.indices.insert(color, index as u8);
+        index as u8
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    pub fn palette(&self) -> &[Rgb] {
+        &self.palette
+    }
+
+    /// The frame with every cell as a square of `scale` by `scale` pixels, like `Canvas::to_image`.
+    pub fn frame(&self, index: usize, scale: usize) -> Image {
+        let mut image = Image::new(self.height * scale, self.width * scale, Rgb::BLACK);
+
+        for (i, &color) in self.frames[index].iter().enumerate() {
+            image.fill_square((i / self.width) * scale, (i % self.width) * scale, scale, self.palette[color as usize]);
+        }
+
+        image
+    }
+
+    /// Writes the frames as `frame_0000.png`, `frame_0001.png` and so on into the directory, which is created when
+    /// missing. Returns the paths of the files.
+    pub fn write_sequence(&self, directory: &Path, format: ImageFormat, scale: usize) -> io::Result<Vec<PathBuf>> {
+        let digits = (self.len().saturating_sub(1).to_string().len()).max(4);
+        fs::create_dir_all(directory)?;
+
+        (0..self.len())
+            .map(|index| {
+                let path = directory.join(format!("frame_{index:0digits$}.{}", format.extension()));
+                fs::write(&path, self.frame(index, scale).encode(format))?;
+
+                Ok(path)
+            })
+            .collect()
+    }
+
+    /// An animated GIF that loops forever and shows every frame for `delay` hundredths of a second.
+    pub fn to_gif(&self, scale: usize, delay: u16) -> Vec<u8> {
+        let (height, width) = (self.height * scale, self.width * scale);
+        assert!(height <= u16::MAX as usize && width <= u16::MAX as usize, "a GIF is at most 65535 pixels wide and high");
+
+        // The color table has a power of two entries, at least two
+        let depth = usize::BITS - (self.palette.len().max(2) - 1).leading_zeros();
+        let min_code_size = depth.max(2) as u8;
+
+        let mut gif = b"GIF89a".to_vec();
+        gif.extend((width as u16).to_le_bytes());
+        gif.extend((height as u16).to_le_bytes());
+        gif.extend([0x80 | ((depth as u8 - 1) << 4) | (depth as u8 - 1), 0, 0]);
+        gif.extend((0..1 << depth).flat_map(|i| {
+            let color = self.palette.get(i).copied().unwrap_or(Rgb::BLACK);
+            [color.r, color.g, color.b]
+        }));
+
+        // Netscape application extension, repeat forever
+        gif.extend(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00");
+
+        for frame in &self.frames {
+            gif.extend([0x21, 0xf9, 4, 0]);
+            gif.extend(delay.to_le_bytes());
+            gif.extend([0, 0]);
+
+            gif.extend([0x2c, 0, 0, 0, 0]);
+            gif.extend((width as u16).to_le_bytes());
+            gif.extend((height as u16).to_le_bytes());
+            gif.push(0);
+
+            let pixels: Vec<u8> = frame
+                .chunks(self.width.max(1))
+                .flat_map(|row| {
+                    let scaled_row: Vec<u8> = row.iter().flat_map(|&index| iter::repeat_n(index, scale)).collect();
+                    scaled_row.repeat(scale)
+                })
+                .collect();
+
+            gif.push(min_code_size);
+            for block in lzw_encode(&pixels, min_code_size).chunks(255) {
+                gif.push(block.len() as u8);
+                gif.extend(block);
+            }
+            gif.push(0);
+        }
+
+        gif.push(0x3b);
+        gif
+    }
+}
+
+/// Writes codes least significant bit first, as GIF packs them.
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    bits: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, size: u32) {
+        self.buffer |= (code as u32) << self.bits;
+        self.bits += size;
+
+        while self.bits >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+
+        self.bytes
+    }
+}
+
+/// The variable length LZW codes of GIF image data. The table starts over when all 4096 codes are used.
+fn lzw_encode(indices: &[u8], min_code_size: u8) -> Vec<u8> {
+    let clear = 1u16 << min_code_size;
+    let end = clear + 1;
+
+    let mut writer = BitWriter::default();
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut next_code = end + 1;
+    let mut code_size = min_code_size as u32 + 1;
+    let mut prefix: Option<u16> = None;
+
+    writer.write(clear, code_size);
+
+    for &index in indices {
+        let Some(code) = prefix else {
+            prefix = Some(index as u16);
+            continue;
+        };
+
+        if let Some(&longer) = table.get(&(code, index)) {
+            prefix = Some(longer);
+            continue;
+        }
+
+        writer.write(code, code_size);
+        table.insert((code, index), next_code);
+
+        if next_code as usize == MAX_CODES - 1 {
+            writer.write(clear, code_size);
+            table.clear();
+            next_code = end + 1;
+            code_size = min_code_size as u32 + 1;
+        } else {
+            if next_code >= 1 << code_size {
+                code_size += 1;
+            }
+
+            next_code += 1;
+        }
+
+        prefix = Some(index as u16);
+    }
+
+    if let Some(code) = prefix {
+        writer.write(code, code_size);
+    }
+
+    writer.write(end, code_size);
+    writer.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+    use crate::render::Cell;
+
+    fn lzw_decode(bytes: &[u8], min_code_size: u8) -> Vec<u8> {
+        let clear = 1usize << min_code_size;
+        let (mut position, mut code_size) = (0, min_code_size as usize + 1);
+        let mut table: Vec<Vec<u8>> = Vec::new();
+        let mut previous: Option<Vec<u8>> = None;
+        let mut decoded = Vec::new();
+
+        loop {
+            let code = (0..code_size).fold(0, |code, bit| {
+                code | (((bytes[(position + bit) / 8] >> ((position + bit) % 8)) as usize & 1) << bit)
+            });
+            position += code_size;
+
+            if code == clear {
+                table = (0..clear + 2).map(|i| vec![i as u8]).collect();
+                (code_size, previous) = (min_code_size as usize + 1, None);
+                continue;
+            } else if code == clear + 1 {
+                return decoded;
+            }
+
+            let entry = match (table.get(code), &previous) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(previous)) => [previous.as_slice(), &previous[..1]].concat(),
+                (None, None) => panic!("code {code} is not in the table"),
+            };
+
+            if let Some(previous) = previous {
+                table.push([previous.as_slice(), &entry[..1]].concat());
+            }
+
+            if table.len() == 1 << code_size && code_size < 12 {
+                code_size += 1;
+            }
+
+            decoded.extend(&entry);
+            previous = Some(entry);
+        }
+    }
+
+    #[test]
+    fn test_lzw() {
+        let repeated = [0, 1, 1, 2, 1, 1, 2, 3].repeat(40);
+        assert_eq!(lzw_decode(&lzw_encode(&repeated, 2), 2), repeated);
+
+        // Enough different sequences to fill the table several times
+        let mut state = 12345u32;
+        let noise: Vec<u8> = (0..50000)
+            .map(|_| {
+                state = state.wrapping_mul(1103515245).wrapping_add(12345);
+                (state >> 16) as u8 % 5
+            })
+            .collect();
+        assert_eq!(lzw_decode(&lzw_encode(&noise, 3), 3), noise);
+        assert!(lzw_decode(&lzw_encode(&[], 2), 2).is_empty());
+    }
+
+    fn frames() -> Recorder {
+        let mut recorder = Recorder::new();
+        let blank = Canvas::new(2, 3, Cell::new('.', Rgb::WHITE));
+
+        for x in 0..3 {
+            recorder.record(&blank.clone().with_cells([(1, x)], Cell::new('O', Rgb::RED)));
+        }
+
+        recorder
+    }
+
+    #[test]
+    fn test_record() {
+        let recorder = frames();
+
+        assert_eq!(recorder.len(), 3);
+        assert_eq!(recorder.palette(), [Rgb::WHITE, Rgb::RED]);
+
+        let image = recorder.frame(1, 2);
+        assert_eq!((image.height(), image.width()), (4, 6));
+        assert_eq!((image.get(2, 1), image.get(3, 2), image.get(3, 4)), (Rgb::WHITE, Rgb::RED, Rgb::WHITE));
+
+        // Past 256 colors the closest one is used
+        let mut many_colors = Recorder::new();
+        let shades = Grid::from_rows((0..=255).map(|i| vec![i]).collect());
+        many_colors.record(&Canvas::from_grid(&shades, |&i| Cell::new('.', Rgb::new(i, 0, 0))));
+        many_colors.record(&Canvas::from_grid(&shades, |&i| Cell::new('.', Rgb::new(i, 0, 3))));
+        assert_eq!(many_colors.palette().len(), 256);
+        assert_eq!(many_colors.frame(1, 1).get(7, 0), Rgb::new(7, 0, 0));
+    }
+
+    #[test]
+    fn test_gif() {
+        let gif = frames().to_gif(2, 25);
+
+        assert_eq!(&gif[..13], b"GIF89a\x06\x00\x04\x00\x80\x00\x00");
+        assert_eq!(&gif[13..19], [255, 255, 255, 220, 50, 47]);
+        assert_eq!(&gif[19..38], b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00");
+        assert_eq!(&gif[38..46], [0x21, 0xf9, 4, 0, 25, 0, 0, 0]);
+        assert_eq!(&gif[46..56], [0x2c, 0, 0, 0, 0, 6, 0, 4, 0, 0]);
+        assert_eq!(gif.last(), Some(&0x3b));
+
+        // The first frame, the rock in the bottom left corner
+        let length = gif[57] as usize;
+        assert_eq!(gif[56], 2);
+        assert_eq!(gif[58 + length], 0);
+        assert_eq!(lzw_decode(&gif[58..58 + length], 2), [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0]);
+    }
+}
@@ -0,0 +1,160 @@
+use std::fmt::{Display, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::{fmt, fs, io};
+use crate::days::Day;
+use crate::input::InputSource;
+use crate::parse::{ParseError, Parser};
+use crate::solution::{Part, PARTS};
+
+/// A known-correct answer for one part of a puzzle input in the day's data directory.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Answer {
+    /// File name of the input, relative to `<data dir>/day<N>/`
+    pub input: String,
+    pub part: Part,
+    pub expected: String,
+}
+
+/// `<data dir>/day<N>/answers.txt`, with lines of `<input file> <part> <answer>`.
+pub fn answers_path(data_dir: &Path, day: u8) -> PathBuf {
+    data_dir.join(format!("day{day}")).join("answers.txt")
+}
+
+pub fn parse_answers(raw_answers: &str) -> Result<Vec<Answer>, ParseError> {
+    let parser = Parser::new(raw_answers);
+
+    parser
+        .lines()
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .map(|l| {
+            let (input, rest) = parser.split_once(l, " ")?;
+            let (part, expected) = parser.split_once(rest, " ")?;
+            let part = part
+                .parse::<Part>()
+                .ok()
+                .filter(|p| PARTS.contains(p))
+                .ok_or_else(|| parser.error(part, "part 1 or 2"))?;
+
+            Ok(Answer {
+                input: input.to_string(),
+                part,
+                expected: expected.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// The registered answers of a day, `None` when the day has no answers file.
+pub fn read_answers(data_dir: &Path, day: u8) -> Result<Option<Vec<Answer>>, AnswersError> {
+    let path = answers_path(data_dir, day);
+
+    let raw_answers = match fs::read_to_string(&path) {
+        Ok(raw_answers) => raw_answers,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(error) => return Err(AnswersError::Io { path, error }),
+    };
+
+    parse_answers(&raw_answers)
+        .map(Some)
+        .map_err(|error| AnswersError::Parse { path, error: error.with_day(day) })
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io { path: PathBuf, error: io::Error },
+    Parse { path: PathBuf, error: ParseError },
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Io { path, error } => write!(f, "could not read answers from `{}`: {error}", path.display()),
+            AnswersError::Parse { path, error } => write!(f, "invalid answers file `{}`: {error}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Mismatch { actual: String },
+    /// The answer could not be computed, because the input is missing or invalid, has no answer, the part does not
+    /// exist or the day panicked.
+    Fail { reason: String },
+}
+
+/// Runs a day on the input of a registered answer and compares the result. A panic in the day fails only this answer.
+pub fn verify(day: &Day, data_dir: &Path, answer: &Answer) -> Verdict {
+    let path = data_dir.join(format!("day{}", day.number)).join(&answer.input);
+
+    let actual = InputSource::File(path)
+        .read(day.number)
+        .map_err(|error| error.to_string())
+        .and_then(|raw_input| {
+            panic::catch_unwind(AssertUnwindSafe(|| (day.run)(answer.part, &raw_input)))
+                .map_err(|payload| {
+                    let message = payload
+                        .downcast_ref::<&str>()
+                        .copied()
+                        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                        .unwrap_or("no message");
+
+                    format!("day {} panicked: {message}", day.number)
+                })?
+                .map_err(|error| error.to_string())
+        });
+
+    match actual {
+        Ok(Some(actual)) if actual == answer.expected => Verdict::Pass,
+        Ok(Some(actual)) => Verdict::Mismatch { actual },
+        Ok(None) => Verdict::Fail { reason: format!("day {} has no part {}", day.number, answer.part) },
+        Err(reason) => Verdict::Fail { reason },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::find_day;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = parse_answers("# input part answer\ninput_example.txt 1 288\n\ninput.txt 2 35865985").unwrap();
+
+        assert_eq!(answers, vec![
+            Answer { input: String::from("input_example.txt"), part: 1, expected: String::from("288") },
+            Answer { input: String::from("input.txt"), part: 2, expected: String::from("35865985") },
+        ]);
+
+        let error = parse_answers("input.txt 3 1").err().unwrap();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (1, 11, "part 1 or 2"));
+    }
+
+    #[test]
+    fn test_verify() {
+        let day = find_day(6).unwrap();
+        let data_dir = Path::new("data");
+        let answer = |input: &str, expected: &str| Answer { input: input.to_string(), part: 1, expected: expected.to_string() };
+
+        assert_eq!(verify(day, data_dir, &answer("input_example.txt", "288")), Verdict::Pass);
+        assert_eq!(verify(day, data_dir, &answer("input_example.txt", "287")), Verdict::Mismatch { actual: String::from("288") });
+        assert!(matches!(verify(day, data_dir, &answer("missing.txt", "288")), Verdict::Fail { .. }));
+    }
+
+    #[test]
+    fn test_verify_panic() {
+        // Day 8 expects the network to have an `AAA` node
+        let data_dir = std::env::temp_dir().join(format!("aoc-answers-{}", std::process::id()));
+        fs::create_dir_all(data_dir.join("day8")).unwrap();
+        fs::write(data_dir.join("day8").join("input.txt"), "L\n\nBBB = (BBB, BBB)").unwrap();
+
+        let answer = Answer { input: String::from("input.txt"), part: 1, expected: String::from("1") };
+        let verdict = verify(find_day(8).unwrap(), &data_dir, &answer);
+        fs::remove_dir_all(&data_dir).unwrap();
+
+        assert!(matches!(verdict, Verdict::Fail { reason } if reason.starts_with("day 8 panicked")));
+    }
+}
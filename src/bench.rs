@@ -0,0 +1,192 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::hint::black_box;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use std::fmt;
+use crate::parse::{ParseError, Parser};
+use crate::solution::{Part, Solution, SolutionError, PARTS};
+
+/// A step of solving a day that is timed separately.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => f.pad("parse"),
+            Stage::Part(part) => f.pad(&format!("part{part}")),
+        }
+    }
+}
+
+impl FromStr for Stage {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Stage::Parse),
+            "part1" => Ok(Stage::Part(1)),
+            "part2" => Ok(Stage::Part(2)),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Measurement {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Measurement {
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "a measurement needs at least one sample");
+        samples.sort();
+
+        let middle = samples.len() / 2;
+        let median = match samples.len() % 2 {
+            0 => (samples[middle - 1] + samples[middle]) / 2,
+            _ => samples[middle],
+        };
+
+        Self {
+            min: samples[0],
+            median,
+            mean: samples.iter().sum::<Duration>() / samples.len() as u32,
+        }
+    }
+}
+
+pub type Measurements = Vec<(Stage, Measurement)>;
+
+/// Runs `f` the given number of times and measures every run.
+fn measure<T>(runs: usize, mut f: impl FnMut() -> T) -> Measurement {
+    let mut samples: Vec<Duration> = (0..runs.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+
+    Measurement::from_samples(&mut samples)
+}
+
+/// Times parsing and both parts of a day separately, the parts share a single parsed input.
+pub fn benchmark<S: Solution>(raw_input: &str, runs: usize) -> Result<Measurements, SolutionError> {
+    let input = S::parse(raw_input).map_err(|error| error.with_day(S::DAY))?;
+    let mut measurements = vec![(Stage::Parse, measure(runs, || S::parse(black_box(raw_input))))];
+
+    for part in PARTS {
+        // Solved once up front, so a part without an answer is reported instead of timed
+        let exists = match part {
+            1 => S::part1(&input).map(|_| true),
+            _ => S::part2(&input).map(|answer| answer.is_some()),
+        };
+
+        if !exists.map_err(|error| error.with_part(S::DAY, part))? {
+            continue;
+        }
+
+        let measurement = match part {
+            1 => measure(runs, || S::part1(black_box(&input))),
+            _ => measure(runs, || S::part2(black_box(&input))),
+        };
+
+        measurements.push((Stage::Part(part), measurement));
+    }
+
+    Ok(measurements)
+}
+
+/// Median timings of an earlier benchmark, stored as lines of `<day> <stage> <median in nanoseconds>`.
+#[derive(Debug, Default, PartialEq)]
+pub struct Baseline {
+    pub medians: BTreeMap<(u8, Stage), Duration>,
+}
+
+impl Baseline {
+    pub fn parse(raw_baseline: &str) -> Result<Self, ParseError> {
+        let parser = Parser::new(raw_baseline);
+
+        let medians = parser
+            .lines()
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+            .map(|l| {
+                let (day, rest) = parser.split_once(l, " ")?;
+                let (stage, median) = parser.split_once(rest, " ")?;
+                let stage = stage
+                    .parse::<Stage>()
+                    .map_err(|_| parser.error(stage, "`parse`, `part1` or `part2`"))?;
+
+                Ok(((parser.number::<u8>(day)?, stage), Duration::from_nanos(parser.number::<u64>(median)?)))
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        Ok(Self { medians })
+    }
+
+    pub fn insert(&mut self, day: u8, stage: Stage, median: Duration) {
+        self.medians.insert((day, stage), median);
+    }
+
+    /// The relative change of a median compared to the baseline, `0.25` is 25% slower.
+    pub fn change(&self, day: u8, stage: Stage, median: Duration) -> Option<f64> {
+        self.medians
+            .get(&(day, stage))
+            .filter(|baseline| !baseline.is_zero())
+            .map(|baseline| median.as_secs_f64() / baseline.as_secs_f64() - 1.0)
+    }
+}
+
+impl Display for Baseline {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# day stage median_ns")?;
+
+        for ((day, stage), median) in &self.medians {
+            writeln!(f, "{day} {stage} {}", median.as_nanos())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measurement_from_samples() {
+        let mut samples = [5, 1, 3, 7].map(Duration::from_millis);
+        let measurement = Measurement::from_samples(&mut samples);
+
+        assert_eq!(measurement.min, Duration::from_millis(1));
+        assert_eq!(measurement.median, Duration::from_millis(4));
+        assert_eq!(measurement.mean, Duration::from_millis(4));
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let mut baseline = Baseline::default();
+        baseline.insert(16, Stage::Part(2), Duration::from_millis(200));
+        baseline.insert(16, Stage::Parse, Duration::from_micros(35));
+
+        let parsed = Baseline::parse(&baseline.to_string()).unwrap();
+
+        assert_eq!(parsed, baseline);
+        assert!((parsed.change(16, Stage::Part(2), Duration::from_millis(250)).unwrap() - 0.25).abs() < 1e-9);
+        assert_eq!(parsed.change(16, Stage::Part(1), Duration::from_millis(250)), None);
+    }
+
+    #[test]
+    fn test_baseline_parse_error() {
+        let error = Baseline::parse("16 parse 100\n16 part3 100").err().unwrap();
+
+        assert_eq!((error.line, error.column, error.found.as_str()), (2, 4, "part3"));
+    }
+}
@@ -0,0 +1,588 @@
+use std::error::Error;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Instant;
+use std::str::FromStr;
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+use log::{debug, LevelFilter};
+use advent_of_code_2023::answers::{read_answers, verify, Verdict};
+use advent_of_code_2023::bench::Baseline;
+use advent_of_code_2023::client::{Client, Submission, BASE_URL};
+use advent_of_code_2023::days::{Day, DAYS, find_day};
+use advent_of_code_2023::image::ImageFormat;
+use advent_of_code_2023::input::{input_path, DEFAULT_DATA_DIR, InputSource};
+use advent_of_code_2023::report::{csv_row, json, SolutionResult, CSV_HEADER};
+use advent_of_code_2023::scaffold::scaffold;
+use advent_of_code_2023::solution::{Part, PARTS};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Log more about what the solutions are doing, repeat for more detail
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+
+    /// Log less, only errors with `-q` and nothing with `-qq`
+    #[arg(short, long, action = ArgAction::Count, global = true, conflicts_with = "verbose")]
+    quiet: u8,
+}
+
+impl Cli {
+    /// Warnings are logged by default, `RUST_LOG` can still narrow the level down per module.
+    fn log_level(&self) -> LevelFilter {
+        const LEVELS: [LevelFilter; 6] = [
+            LevelFilter::Off,
+            LevelFilter::Error,
+            LevelFilter::Warn,
+            LevelFilter::Info,
+            LevelFilter::Debug,
+            LevelFilter::Trace,
+        ];
+
+        LEVELS[(2 + self.verbose as usize).saturating_sub(self.quiet as usize).min(LEVELS.len() - 1)]
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solutions of a single day or of all days
+    Run {
+        /// Day number (1-25) or `all`
+        day: DaySelection,
+
+        /// Only run this part of the puzzle
+        #[arg(short, long, value_parser = clap::value_parser!(Part).range(1..=2))]
+        part: Option<Part>,
+
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// Print the answers with their input and timing as JSON or CSV
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+
+    /// Time parsing and solving of a single day or of all days
+    Bench {
+        /// Day number (1-25) or `all`
+        day: DaySelection,
+
+        /// Number of times parsing and every part are run
+        #[arg(short = 'n', long, default_value_t = 10)]
+        runs: usize,
+
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// Compare the medians with a baseline saved by an earlier run
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+
+        /// Save the medians as a baseline for later runs
+        #[arg(long)]
+        save_baseline: Option<PathBuf>,
+
+        /// Report a regression when a median is more than this percentage slower than the baseline
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+
+    /// Check the solutions against the answers registered in `<DATA_DIR>/day<N>/answers.txt`
+    Verify {
+        /// Day number (1-25) or `all`
+        #[arg(default_value = "all")]
+        day: DaySelection,
+
+        #[arg(long, default_value = DEFAULT_DATA_DIR)]
+        data_dir: PathBuf,
+    },
+
+    /// Download puzzle inputs into `<DATA_DIR>/day<N>/input.txt`, inputs that are already there are kept
+    Fetch {
+        /// Day number (1-25) or `all`
+        day: DaySelection,
+
+        /// Download the input again, even when it is already in the data directory
+        #[arg(long)]
+        force: bool,
+
+        #[command(flatten)]
+        server: ServerArgs,
+    },
+
+    /// Submit an answer, by default the one the solution gives for the day's input
+    Submit {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        #[arg(value_parser = clap::value_parser!(Part).range(1..=2))]
+        part: Part,
+
+        answer: Option<String>,
+
+        #[command(flatten)]
+        server: ServerArgs,
+    },
+
+    /// Create the solution module and data files of a new day from the day 0 template
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Root directory of the crate
+        #[arg(long, default_value = ".")]
+        root: PathBuf,
+    },
+
+    /// Draw how a day's puzzle is solved, for the days that support it
+    Render {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// The part of the puzzle to draw
+        #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(Part).range(1..=2))]
+        part: Part,
+
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// Plain text, text in terminal colors or an image
+        #[arg(long, value_enum, default_value_t = RenderFormat::Ansi)]
+        format: RenderFormat,
+
+        /// Width and height in pixels of a grid cell in images
+        #[arg(long, default_value_t = 4)]
+        scale: usize,
+
+        /// Write to this file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Record every step of a day's simulation as an animated GIF or numbered images, for the days that support it
+    Animate {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// The part of the puzzle to record
+        #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(Part).range(1..=2))]
+        part: Part,
+
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// An animated GIF, or a numbered PNG or PPM image per frame
+        #[arg(long, value_enum, default_value_t = AnimationFormat::Gif)]
+        format: AnimationFormat,
+
+        /// Width and height in pixels of a grid cell
+        #[arg(long, default_value_t = 4)]
+        scale: usize,
+
+        /// Hundredths of a second every frame of a GIF is shown
+        #[arg(long, default_value_t = 10)]
+        delay: u16,
+
+        /// Write the GIF to this file instead of stdout, or the images into this directory
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// List all available days
+    List,
+}
+
+#[derive(Args)]
+struct ServerArgs {
+    /// Value of the `session` cookie of a logged-in browser
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: String,
+
+    #[arg(long, env = "AOC_BASE_URL", default_value = BASE_URL)]
+    base_url: String,
+
+    #[arg(long, default_value = DEFAULT_DATA_DIR)]
+    data_dir: PathBuf,
+}
+
+impl ServerArgs {
+    fn client(&self) -> Client {
+        Client::new(&self.session, &self.data_dir).with_base_url(&self.base_url)
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum RenderFormat {
+    Text,
+    Ansi,
+    Ppm,
+    Png,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum AnimationFormat {
+    Gif,
+    Png,
+    Ppm,
+}
+
+#[derive(Args)]
+struct InputArgs {
+    /// Read the puzzle input from this file, or from stdin when `-`
+    #[arg(short, long)]
+    input: Option<String>,
+
+    /// Read the puzzle inputs from `<DATA_DIR>/day<N>/input.txt`
+    #[arg(long, default_value = DEFAULT_DATA_DIR)]
+    data_dir: PathBuf,
+}
+
+impl InputArgs {
+    fn source(self, days: &[&Day]) -> Result<InputSource, String> {
+        match self.input {
+            Some(_) if days.len() > 1 => Err(String::from("--input can only be used when running a single day")),
+            Some(arg) => Ok(InputSource::from_arg(&arg)),
+            None => Ok(InputSource::DataDir(self.data_dir)),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum DaySelection {
+    All,
+    Single(u8),
+}
+
+impl DaySelection {
+    fn days(self) -> Vec<&'static Day> {
+        match self {
+            DaySelection::All => DAYS.iter().collect(),
+            DaySelection::Single(number) => find_day(number).into_iter().collect(),
+        }
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(DaySelection::All);
+        }
+
+        match s.parse::<u8>() {
+            Ok(number) if find_day(number).is_some() => Ok(DaySelection::Single(number)),
+            _ => Err(format!("expected `all` or a day between 1 and {}", DAYS.len())),
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    env_logger::Builder::new()
+        .filter_level(cli.log_level())
+        .parse_default_env()
+        .init();
+
+    let result = match cli.command {
+        Command::Run { day, part, input, format } => run(day, part, input, format),
+        Command::Bench { day, runs, input, baseline, save_baseline, threshold } => {
+            bench(day, runs, input, baseline.as_deref(), save_baseline.as_deref(), threshold)
+        },
+        Command::Verify { day, data_dir } => verify_answers(day, &data_dir),
+        Command::Fetch { day, force, server } => fetch(day, force, &server),
+        Command::Submit { day, part, answer, server } => submit(day, part, answer, &server),
+        Command::New { day, root } => new_day(day, &root),
+        Command::Render { day, part, input, format, scale, output } => render(day, part, input, format, scale, output.as_deref()),
+        Command::Animate { day, part, input, format, scale, delay, output } => {
+            animate(day, part, input, format, scale, delay, output.as_deref())
+        },
+        Command::List => {
+            for day in DAYS.iter() {
+                println!("Day {}", day.number);
+            }
+
+            Ok(ExitCode::SUCCESS)
+        },
+    };
+
+    result.unwrap_or_else(|error| {
+        eprintln!("error: {error}");
+        ExitCode::FAILURE
+    })
+}
+
+fn run(selection: DaySelection, part: Option<Part>, input: InputArgs, format: Format) -> Result<ExitCode, Box<dyn Error>> {
+    let days = selection.days();
+    let source = input.source(&days)?;
+    let mut results = Vec::new();
+
+    if let Format::Csv = format {
+        println!("{CSV_HEADER}");
+    }
+
+    for day in days {
+        let raw_input = source.read(day.number)?;
+
+        if let Format::Text = format {
+            println!("Day {}", day.number);
+        }
+
+        for p in PARTS.into_iter().filter(|&p| part.is_none_or(|only| only == p)) {
+            let start = Instant::now();
+            let answer = (day.run)(p, &raw_input)?;
+            let result = SolutionResult::new(day.number, p, answer, &source.describe(day.number), start.elapsed());
+
+            debug!("Day {} part {p} took {:?}", day.number, start.elapsed());
+
+            match format {
+                Format::Text => println!("Puzzle {p}: {}", result.answer.as_deref().unwrap_or("-")),
+                Format::Csv => println!("{}", csv_row(&result)),
+                Format::Json => results.push(result),
+            }
+        }
+    }
+
+    if let Format::Json = format {
+        println!("{}", json(&results));
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn bench(
+    selection: DaySelection,
+    runs: usize,
+    input: InputArgs,
+    baseline_path: Option<&Path>,
+    save_baseline_path: Option<&Path>,
+    threshold: f64,
+) -> Result<ExitCode, Box<dyn Error>> {
+    let days = selection.days();
+    let source = input.source(&days)?;
+    let baseline = baseline_path.map(read_baseline).transpose()?;
+
+    let mut new_baseline = Baseline::default();
+    let mut regressions = 0;
+
+    for day in days {
+        let raw_input = source.read(day.number)?;
+
+        println!("Day {}", day.number);
+
+        for (stage, measurement) in (day.bench)(&raw_input, runs)? {
+            print!(
+                "  {stage:<6} min {:>10.2?}  median {:>10.2?}  mean {:>10.2?}",
+                measurement.min, measurement.median, measurement.mean,
+            );
+
+            match baseline.as_ref().and_then(|b| b.change(day.number, stage, measurement.median)) {
+                Some(change) if change * 100.0 > threshold => {
+                    regressions += 1;
+                    println!("  {:+.1}% regression", change * 100.0);
+                },
+                Some(change) => println!("  {:+.1}%", change * 100.0),
+                None => println!(),
+            }
+
+            new_baseline.insert(day.number, stage, measurement.median);
+        }
+    }
+
+    if let Some(path) = save_baseline_path {
+        fs::write(path, new_baseline.to_string())
+            .map_err(|error| format!("could not write baseline to `{}`: {error}", path.display()))?;
+    }
+
+    if regressions > 0 {
+        eprintln!("{regressions} stage(s) regressed by more than {threshold}%");
+        return Ok(ExitCode::FAILURE);
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn verify_answers(selection: DaySelection, data_dir: &Path) -> Result<ExitCode, Box<dyn Error>> {
+    let (mut passed, mut mismatched, mut failed) = (0, 0, 0);
+
+    for day in selection.days() {
+        let Some(answers) = read_answers(data_dir, day.number)? else {
+            println!("Day {}: no answers registered", day.number);
+            continue;
+        };
+
+        for answer in answers {
+            let label = format!("Day {} part {} ({})", day.number, answer.part, answer.input);
+
+            match verify(day, data_dir, &answer) {
+                Verdict::Pass => {
+                    passed += 1;
+                    println!("{label}: pass");
+                },
+                Verdict::Mismatch { actual } => {
+                    mismatched += 1;
+                    println!("{label}: mismatch, expected {} but got {actual}", answer.expected);
+                },
+                Verdict::Fail { reason } => {
+                    failed += 1;
+                    println!("{label}: fail, {reason}");
+                },
+            }
+        }
+    }
+
+    println!("{passed} passed, {mismatched} mismatched, {failed} failed");
+
+    match mismatched + failed {
+        0 => Ok(ExitCode::SUCCESS),
+        _ => Ok(ExitCode::FAILURE),
+    }
+}
+
+fn fetch(selection: DaySelection, force: bool, server: &ServerArgs) -> Result<ExitCode, Box<dyn Error>> {
+    let mut client = server.client();
+
+    for day in selection.days() {
+        let raw_input = client.fetch_input(day.number, force)?;
+
+        let path = input_path(&server.data_dir, day.number);
+
+        println!("Day {}: {} lines in {}", day.number, raw_input.lines().count(), path.display());
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn submit(number: u8, part: Part, answer: Option<String>, server: &ServerArgs) -> Result<ExitCode, Box<dyn Error>> {
+    let day = find_day(number).ok_or_else(|| format!("there is no solution for day {number}"))?;
+
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let raw_input = InputSource::DataDir(server.data_dir.clone()).read(day.number)?;
+
+            (day.run)(part, &raw_input)?.ok_or_else(|| format!("day {} has no part {part}", day.number))?
+        },
+    };
+
+    println!("Day {} part {part}: submitting {answer}", day.number);
+
+    match server.client().submit(day.number, part, &answer)? {
+        Submission::Correct => println!("That's the right answer"),
+        Submission::Incorrect { hint: Some(hint) } => println!("That's not the right answer, it is {hint}"),
+        Submission::Incorrect { hint: None } => println!("That's not the right answer"),
+        Submission::WrongLevel => println!("Part {part} is already solved or not unlocked yet"),
+        Submission::TooSoon { wait } => println!("An answer was given too recently, wait {}s", wait.as_secs()),
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn render(
+    number: u8,
+    part: Part,
+    input: InputArgs,
+    format: RenderFormat,
+    scale: usize,
+    output: Option<&Path>,
+) -> Result<ExitCode, Box<dyn Error>> {
+    let day = find_day(number).ok_or_else(|| format!("there is no solution for day {number}"))?;
+    let raw_input = input.source(&[day])?.read(day.number)?;
+
+    let canvas = (day.render)(part, &raw_input)?
+        .ok_or_else(|| format!("day {} part {part} has no rendering", day.number))?;
+
+    let bytes = match format {
+        RenderFormat::Text => format!("{}\n", canvas.to_text()).into_bytes(),
+        RenderFormat::Ansi => canvas.to_ansi().into_bytes(),
+        RenderFormat::Ppm => canvas.to_image(scale).to_ppm(),
+        RenderFormat::Png => canvas.to_image(scale).to_png(),
+    };
+
+    // Images can not show the legend, so it goes next to them
+    if matches!(format, RenderFormat::Ppm | RenderFormat::Png) {
+        for (color, label) in canvas.legend() {
+            eprintln!("#{:02x}{:02x}{:02x} {label}", color.r, color.g, color.b);
+        }
+    }
+
+    match output {
+        Some(path) => fs::write(path, bytes)
+            .map_err(|error| format!("could not write rendering to `{}`: {error}", path.display()))?,
+        None => io::stdout().write_all(&bytes)?,
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn animate(
+    number: u8,
+    part: Part,
+    input: InputArgs,
+    format: AnimationFormat,
+    scale: usize,
+    delay: u16,
+    output: Option<&Path>,
+) -> Result<ExitCode, Box<dyn Error>> {
+    let day = find_day(number).ok_or_else(|| format!("there is no solution for day {number}"))?;
+    let raw_input = input.source(&[day])?.read(day.number)?;
+
+    let recorder = (day.animate)(part, &raw_input)?
+        .ok_or_else(|| format!("day {} part {part} has no animation", day.number))?;
+
+    let image_format = match format {
+        AnimationFormat::Gif => {
+            let gif = recorder.to_gif(scale, delay);
+
+            match output {
+                Some(path) => fs::write(path, gif)
+                    .map_err(|error| format!("could not write animation to `{}`: {error}", path.display()))?,
+                None => io::stdout().write_all(&gif)?,
+            }
+
+            return Ok(ExitCode::SUCCESS);
+        },
+        AnimationFormat::Png => ImageFormat::Png,
+        AnimationFormat::Ppm => ImageFormat::Ppm,
+    };
+
+    let directory = output.ok_or("--output is needed to know where to write the images")?;
+    let paths = recorder
+        .write_sequence(directory, image_format, scale)
+        .map_err(|error| format!("could not write frames to `{}`: {error}", directory.display()))?;
+
+    println!("Wrote {} frames to {}", paths.len(), directory.display());
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn new_day(day: u8, root: &Path) -> Result<ExitCode, Box<dyn Error>> {
+    for path in scaffold(root, day)? {
+        println!("Created {}", path.display());
+    }
+
+    println!("Registered day {day} in src/days/mod.rs");
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn read_baseline(path: &Path) -> Result<Baseline, String> {
+    let raw_baseline = fs::read_to_string(path)
+        .map_err(|error| format!("could not read baseline from `{}`: {error}", path.display()))?;
+
+    Baseline::parse(&raw_baseline)
+        .map_err(|error| format!("invalid baseline `{}`: {error}", path.display()))
+}
@@ -0,0 +1,284 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+use std::fmt;
+use crate::{Coordinate, Direction};
+use crate::grid::Grid;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A grid of booleans packed 64 cells to a word, so whole rows can be shifted and combined at once. Every row starts
+/// at a new word and the bits past the width are always 0.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    height: usize,
+    width: usize,
+    row_words: usize,
+}
+
+impl BitGrid {
+    /// All cells cleared.
+    pub fn new(height: usize, width: usize) -> Self {
+        let row_words = width.div_ceil(WORD_BITS);
+
+        Self { words: vec![0; height * row_words], height, width, row_words }
+    }
+
+    pub fn from_fn(height: usize, width: usize, mut f: impl FnMut(Coordinate) -> bool) -> Self {
+        let mut bit_grid = Self::new(height, width);
+
+        for y in 0..height {
+            for x in 0..width {
+                if f((y, x)) {
+                    bit_grid.insert((y, x));
+                }
+            }
+        }
+
+        bit_grid
+    }
+
+    /// Sets the cells that match the predicate.
+    pub fn from_grid<T>(grid: &Grid<T>, mut predicate: impl FnMut(&T) -> bool) -> Self {
+        Self::from_fn(grid.height(), grid.width(), |c| predicate(&grid[c]))
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn contains(&self, (y, x): Coordinate) -> bool {
+        y < self.height && x < self.width
+    }
+
+    fn word_and_mask(&self, (y, x): Coordinate) -> (usize, u64) {
+        assert!(self.contains((y, x)), "coordinate ({y}, {x}) is outside a bit grid of {}x{}", self.height, self.width);
+
+        (y * self.row_words + x / WORD_BITS, 1 << (x % WORD_BITS))
+    }
+
+    /// Panics when the coordinate is outside the grid.
+    pub fn get(&self, coordinate: Coordinate) -> bool {
+        let (word, mask) = self.word_and_mask(coordinate);
+
+        self.words[word] & mask != 0
+    }
+
+    pub fn set(&mut self, coordinate: Coordinate, value: bool) {
+        let (word, mask) = self.word_and_mask(coordinate);
+
+        if value {
+            self.words[word] |= mask;
+        } else {
+            self.words[word] &= !mask;
+        }
+    }
+
+    /// Sets the cell, returns whether it was not set before.
+    pub fn insert(&mut self, coordinate: Coordinate) -> bool {
+        let (word, mask) = self.word_and_mask(coordinate);
+        let was_set = self.words[word] & mask != 0;
+        self.words[word] |= mask;
+
+        !was_set
+    }
+
+    /// Whether any cell is set.
+    pub fn any(&self) -> bool {
+        self.words.iter().any(|&w| w != 0)
+    }
+
+    /// The number of cells that are set.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// The coordinates of the cells that are set, row by row.
+    pub fn ones(&self) -> impl Iterator<Item = Coordinate> + '_ {
+        self.words.iter().enumerate().flat_map(move |(i, &word)| {
+            let (y, first_x) = (i / self.row_words, (i % self.row_words) * WORD_BITS);
+
+            (0..WORD_BITS).filter(move |bit| word & (1 << bit) != 0).map(move |bit| (y, first_x + bit))
+        })
+    }
+
+    /// Moves every cell one step in the direction, cells that leave the grid are dropped.
+    pub fn shifted(&self, direction: Direction) -> Self {
+        let vector = direction.unit_vector();
+        let mut shifted = self.clone();
+
+        match vector.y {
+            -1 => {
+                shifted.words.copy_within(self.row_words.., 0);
+                shifted.words[(self.height.saturating_sub(1)) * self.row_words..].fill(0);
+            },
+            1 => {
+                shifted.words.copy_within(..self.words.len().saturating_sub(self.row_words), self.row_words);
+                shifted.words[..self.row_words.min(self.words.len())].fill(0);
+            },
+            _ => {},
+        }
+
+        for row in shifted.words.chunks_mut(self.row_words.max(1)) {
+            match vector.x {
+                1 => {
+                    let mut carry = 0;
+                    for word in row.iter_mut() {
+                        (*word, carry) = ((*word << 1) | carry, *word >> (WORD_BITS - 1));
+                    }
+                },
+                -1 => {
+                    let mut carry = 0;
+                    for word in row.iter_mut().rev() {
+                        (*word, carry) = ((*word >> 1) | (carry << (WORD_BITS - 1)), *word & 1);
+                    }
+                },
+                _ => {},
+            }
+        }
+
+        shifted.clear_padding();
+        shifted
+    }
+
+    /// The cells orthogonally next to a set cell.
+    pub fn neighbors(&self) -> Self {
+        Direction::ORTHOGONAL
+            .into_iter()
+            .fold(Self::new(self.height, self.width), |neighbors, direction| &neighbors | &self.shifted(direction))
+    }
+
+    /// The set cells together with their orthogonal neighbors.
+    pub fn expand(&self) -> Self {
+        self | &self.neighbors()
+    }
+
+    fn clear_padding(&mut self) {
+        let used_bits = self.width % WORD_BITS;
+
+        if used_bits != 0 {
+            let mask = (1 << used_bits) - 1;
+            self.words.chunks_mut(self.row_words).for_each(|row| *row.last_mut().unwrap() &= mask);
+        }
+    }
+
+    fn combine(&mut self, other: &Self, f: impl Fn(u64, u64) -> u64) {
+        assert!((self.height, self.width) == (other.height, other.width), "bit grids must have the same size");
+
+        self.words.iter_mut().zip(&other.words).for_each(|(a, &b)| *a = f(*a, b));
+    }
+}
+
+macro_rules! impl_bit_operator {
+    ($operator:ident, $method:ident, $assign_operator:ident, $assign_method:ident, $op:tt) => {
+        impl $assign_operator<&BitGrid> for BitGrid {
+            fn $assign_method(&mut self, other: &BitGrid) {
+                self.combine(other, |a, b| a $op b);
+            }
+        }
+
+        impl $operator for &BitGrid {
+            type Output = BitGrid;
+
+            fn $method(self, other: Self) -> Self::Output {
+                let mut result = self.clone();
+                result.combine(other, |a, b| a $op b);
+                result
+            }
+        }
+    };
+}
+
+impl_bit_operator!(BitAnd, bitand, BitAndAssign, bitand_assign, &);
+impl_bit_operator!(BitOr, bitor, BitOrAssign, bitor_assign, |);
+impl_bit_operator!(BitXor, bitxor, BitXorAssign, bitxor_assign, ^);
+
+impl Not for &BitGrid {
+    type Output = BitGrid;
+
+    fn not(self) -> Self::Output {
+        let mut result = self.clone();
+        result.words.iter_mut().for_each(|w| *w = !*w);
+        result.clear_padding();
+        result
+    }
+}
+
+/// Set cells are written as `#`, cleared cells as `.`.
+impl Display for BitGrid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for x in 0..self.width {
+                write!(f, "{}", if self.get((y, x)) { '#' } else { '.' })?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Direction::{East, North, SouthWest, West};
+
+    fn parse(rows: &str) -> BitGrid {
+        BitGrid::from_grid(&Grid::from_lines(rows), |&c| c == '#')
+    }
+
+    #[test]
+    fn test_cells() {
+        let mut grid = BitGrid::new(2, 70);
+
+        assert!(grid.insert((1, 65)));
+        assert!(!grid.insert((1, 65)));
+        grid.set((0, 3), true);
+        assert!(grid.get((0, 3)) && !grid.get((1, 3)));
+        assert_eq!(grid.ones().collect::<Vec<_>>(), vec![(0, 3), (1, 65)]);
+        assert_eq!((grid.count_ones(), (!&grid).count_ones()), (2, 138));
+
+        grid.set((0, 3), false);
+        assert_eq!(grid.count_ones(), 1);
+    }
+
+    #[test]
+    fn test_shifts() {
+        let grid = parse("#..#\n.#..\n...#");
+
+        assert_eq!(grid.shifted(East).to_string(), ".#..\n..#.\n....");
+        assert_eq!(grid.shifted(West).to_string(), "..#.\n#...\n..#.");
+        assert_eq!(grid.shifted(North).to_string(), ".#..\n...#\n....");
+        assert_eq!(grid.shifted(SouthWest).to_string(), "....\n..#.\n#...");
+        assert_eq!(parse(".#.\n...\n...").expand().to_string(), "###\n.#.\n...");
+
+        // Bits carry across words
+        let mut wide = BitGrid::new(1, 130);
+        wide.insert((0, 63));
+        wide.insert((0, 129));
+        assert_eq!(wide.shifted(East).ones().collect::<Vec<_>>(), vec![(0, 64)]);
+        assert_eq!(wide.shifted(West).ones().collect::<Vec<_>>(), vec![(0, 62), (0, 128)]);
+    }
+
+    #[test]
+    fn test_operators() {
+        let a = parse("##..\n#...");
+        let b = parse(".##.\n#..#");
+
+        assert_eq!((&a & &b).to_string(), ".#..\n#...");
+        assert_eq!((&a | &b).to_string(), "###.\n#..#");
+        assert_eq!((&a ^ &b).to_string(), "#.#.\n...#");
+        assert_eq!((!&a).to_string(), "..##\n.###");
+
+        let mut c = a.clone();
+        c ^= &a;
+        assert!(!c.any());
+    }
+}
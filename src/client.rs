@@ -0,0 +1,319 @@
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::thread::sleep;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use std::{fmt, fs, io};
+use ureq::{Agent, AgentBuilder};
+use crate::input::input_path;
+use crate::solution::Part;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2023;
+pub const USER_AGENT: &str = concat!("advent_of_code_2023/", env!("CARGO_PKG_VERSION"));
+
+/// Minimum time between two requests to the server.
+pub const REQUEST_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Stores until when the server refuses new answers, so later runs wait without asking the server again.
+const SUBMIT_LOCK_FILE: &str = ".submit_lock";
+
+/// Downloads puzzle inputs into the data directory and submits answers, using the session cookie of a logged-in user.
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+    data_dir: PathBuf,
+    request_interval: Duration,
+    last_request: Option<Instant>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Submission {
+    Correct,
+    /// Too high or too low, when the server tells
+    Incorrect { hint: Option<String> },
+    /// The part was solved before, or part 1 has to be solved first.
+    WrongLevel,
+    TooSoon { wait: Duration },
+}
+
+impl Client {
+    pub fn new(session: &str, data_dir: &Path) -> Self {
+        Self {
+            agent: AgentBuilder::new().user_agent(USER_AGENT).timeout(Duration::from_secs(30)).build(),
+            base_url: String::from(BASE_URL),
+            session: session.trim().to_string(),
+            data_dir: data_dir.to_path_buf(),
+            request_interval: REQUEST_INTERVAL,
+            last_request: None,
+        }
+    }
+
+    /// Talks to another server than adventofcode.com, like a local stub.
+    pub fn with_base_url(self, base_url: &str) -> Self {
+        Self { base_url: base_url.trim_end_matches('/').to_string(), ..self }
+    }
+
+    pub fn with_request_interval(self, request_interval: Duration) -> Self {
+        Self { request_interval, ..self }
+    }
+
+    /// Returns the input of a day from the data directory, and only downloads it when it is not there yet.
+    pub fn fetch_input(&mut self, day: u8, force: bool) -> Result<String, ClientError> {
+        let path = input_path(&self.data_dir, day);
+
+        if !force {
+            match fs::read_to_string(&path) {
+                Ok(raw_input) => return Ok(raw_input),
+                Err(error) if error.kind() == io::ErrorKind::NotFound => {},
+                Err(error) => return Err(ClientError::Io { path, error }),
+            }
+        }
+
+        let url = format!("{}/{YEAR}/day/{day}/input", self.base_url);
+        self.throttle();
+
+        let raw_input = self.agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|error| ClientError::from_ureq(&url, error))?
+            .into_string()
+            .map_err(|error| ClientError::Transport { url: url.clone(), message: error.to_string() })?;
+
+        // Stored without the trailing newline, like the other inputs in the data directory
+        let raw_input = raw_input.trim_end_matches(['\n', '\r']).to_string();
+
+        write_file(&path, &raw_input)?;
+
+        Ok(raw_input)
+    }
+
+    pub fn submit(&mut self, day: u8, part: Part, answer: &str) -> Result<Submission, ClientError> {
+        let lock_path = self.data_dir.join(SUBMIT_LOCK_FILE);
+
+        if let Some(wait) = read_submit_lock(&lock_path) {
+            return Ok(Submission::TooSoon { wait });
+        }
+
+        let url = format!("{}/{YEAR}/day/{day}/answer", self.base_url);
+        self.throttle();
+
+        let body = self.agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|error| ClientError::from_ureq(&url, error))?
+            .into_string()
+            .map_err(|error| ClientError::Transport { url: url.clone(), message: error.to_string() })?;
+
+        let submission = parse_submission(&body)
+            .ok_or_else(|| ClientError::UnexpectedResponse { url: url.clone(), body: article(&body).to_string() })?;
+
+        if let Submission::TooSoon { wait } = submission {
+            let until = SystemTime::now() + wait;
+            let seconds = until.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+
+            write_file(&lock_path, &seconds.to_string())?;
+        }
+
+        Ok(submission)
+    }
+
+    /// Waits until the request interval has passed since the previous request.
+    fn throttle(&mut self) {
+        if let Some(elapsed) = self.last_request.map(|last_request| last_request.elapsed()) {
+            sleep(self.request_interval.saturating_sub(elapsed));
+        }
+
+        self.last_request = Some(Instant::now());
+    }
+}
+
+/// The remaining time of a lock written after the server said to wait, `None` when there is no lock or it expired.
+fn read_submit_lock(path: &Path) -> Option<Duration> {
+    let seconds = fs::read_to_string(path).ok()?.trim().parse::<u64>().ok()?;
+
+    (UNIX_EPOCH + Duration::from_secs(seconds)).duration_since(SystemTime::now()).ok()
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), ClientError> {
+    path.parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(path, contents))
+        .map_err(|error| ClientError::Io { path: path.to_path_buf(), error })
+}
+
+/// The message is in the `<article>` of the returned page.
+fn article(body: &str) -> &str {
+    body
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(body, |(article, _)| article)
+}
+
+fn parse_submission(body: &str) -> Option<Submission> {
+    let message = article(body);
+
+    if message.contains("That's the right answer") {
+        Some(Submission::Correct)
+    } else if message.contains("That's not the right answer") {
+        let hint = ["too high", "too low"].into_iter().find(|hint| message.contains(hint)).map(String::from);
+
+        Some(Submission::Incorrect { hint })
+    } else if message.contains("You don't seem to be solving the right level") {
+        Some(Submission::WrongLevel)
+    } else if message.contains("You gave an answer too recently") {
+        Some(Submission::TooSoon { wait: parse_wait(message).unwrap_or(Duration::from_secs(60)) })
+    } else {
+        None
+    }
+}
+
+/// Reads the wait time from "you have 1m 23s left to wait".
+fn parse_wait(message: &str) -> Option<Duration> {
+    let (before, _) = message.split_once(" left to wait")?;
+    let (_, time) = before.rsplit_once("you have ")?;
+
+    time
+        .split_whitespace()
+        .map(|amount| match amount.split_at_checked(amount.len().checked_sub(1)?)? {
+            (minutes, "m") => minutes.parse::<u64>().ok().map(|m| m * 60),
+            (seconds, "s") => seconds.parse::<u64>().ok(),
+            _ => None,
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    Status { url: String, status: u16, body: String },
+    Transport { url: String, message: String },
+    UnexpectedResponse { url: String, body: String },
+    Io { path: PathBuf, error: io::Error },
+}
+
+impl ClientError {
+    fn from_ureq(url: &str, error: ureq::Error) -> Self {
+        match error {
+            ureq::Error::Status(status, response) => ClientError::Status {
+                url: url.to_string(),
+                status,
+                body: response.into_string().unwrap_or_default().trim().to_string(),
+            },
+            ureq::Error::Transport(transport) => ClientError::Transport { url: url.to_string(), message: transport.to_string() },
+        }
+    }
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::Status { url, status: 400, .. } => write!(f, "request to `{url}` was refused (400), is the session token valid?"),
+            ClientError::Status { url, status: 404, .. } => write!(f, "`{url}` was not found (404), is the puzzle unlocked yet?"),
+            ClientError::Status { url, status, body } => write!(f, "request to `{url}` failed with status {status}: {body}"),
+            ClientError::Transport { url, message } => write!(f, "request to `{url}` failed: {message}"),
+            ClientError::UnexpectedResponse { url, body } => write!(f, "unexpected response from `{url}`: {}", body.trim()),
+            ClientError::Io { path, error } => write!(f, "could not write `{}`: {error}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+    use std::thread;
+    use tiny_http::{Header, Response, Server};
+    use super::*;
+
+    /// A stub of the puzzle server that answers every request with the next canned response and reports what it got.
+    fn stub_server(responses: Vec<(u16, &'static str)>) -> (String, mpsc::Receiver<(String, String, String)>) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let mut request = server.recv().unwrap();
+                let cookie = request.headers().iter().find(|h| h.field.equiv("Cookie")).map(|h| h.value.to_string()).unwrap_or_default();
+                let mut content = String::new();
+                request.as_reader().read_to_string(&mut content).unwrap();
+
+                sender.send((format!("{} {}", request.method(), request.url()), cookie, content)).unwrap();
+
+                let header = Header::from_bytes("Content-Type", "text/html").unwrap();
+                request.respond(Response::from_string(body).with_status_code(status).with_header(header)).unwrap();
+            }
+        });
+
+        (url, receiver)
+    }
+
+    fn temp_data_dir(name: &str) -> PathBuf {
+        let data_dir = std::env::temp_dir().join(format!("aoc-client-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&data_dir);
+
+        data_dir
+    }
+
+    #[test]
+    fn test_fetch_input_is_cached() {
+        let (url, requests) = stub_server(vec![(200, "1 2 3\n4 5 6\n")]);
+        let data_dir = temp_data_dir("fetch");
+        let mut client = Client::new("abc", &data_dir).with_base_url(&url).with_request_interval(Duration::ZERO);
+
+        assert_eq!(client.fetch_input(9, false).unwrap(), "1 2 3\n4 5 6");
+        assert_eq!(requests.recv().unwrap(), (String::from("GET /2023/day/9/input"), String::from("session=abc"), String::new()));
+        assert_eq!(fs::read_to_string(data_dir.join("day9/input.txt")).unwrap(), "1 2 3\n4 5 6");
+
+        // The second fetch is served from the data directory, the stub would not answer it
+        assert_eq!(client.fetch_input(9, false).unwrap(), "1 2 3\n4 5 6");
+        assert!(requests.try_recv().is_err());
+
+        fs::remove_dir_all(data_dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_input_error() {
+        let (url, _requests) = stub_server(vec![(400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.")]);
+        let data_dir = temp_data_dir("fetch-error");
+        let mut client = Client::new("expired", &data_dir).with_base_url(&url);
+
+        let error = client.fetch_input(1, false).unwrap_err();
+
+        assert!(matches!(error, ClientError::Status { status: 400, .. }));
+        assert!(!data_dir.join("day1/input.txt").exists());
+    }
+
+    #[test]
+    fn test_submit() {
+        let (url, requests) = stub_server(vec![
+            (200, "<main><article><p>That's not the right answer; your answer is too low.</p></article></main>"),
+            (200, "<main><article><p>You gave an answer too recently; you have 1m 5s left to wait.</p></article></main>"),
+        ]);
+        let data_dir = temp_data_dir("submit");
+        let mut client = Client::new("abc", &data_dir).with_base_url(&url).with_request_interval(Duration::ZERO);
+
+        assert_eq!(client.submit(6, 2, "1234").unwrap(), Submission::Incorrect { hint: Some(String::from("too low")) });
+        assert_eq!(requests.recv().unwrap(), (String::from("POST /2023/day/6/answer"), String::from("session=abc"), String::from("level=2&answer=1234")));
+
+        assert!(matches!(client.submit(6, 2, "1235").unwrap(), Submission::TooSoon { wait } if wait == Duration::from_secs(65)));
+
+        // The lock is respected without asking the server
+        assert!(matches!(client.submit(6, 2, "1236").unwrap(), Submission::TooSoon { wait } if wait <= Duration::from_secs(65)));
+        assert_eq!(requests.iter().count(), 1);
+
+        fs::remove_dir_all(data_dir).unwrap();
+    }
+
+    #[test]
+    fn test_parse_submission() {
+        assert_eq!(parse_submission("<article><p>That's the right answer! You are one gold star closer.</p></article>"), Some(Submission::Correct));
+        assert_eq!(parse_submission("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>"), Some(Submission::WrongLevel));
+        assert_eq!(parse_submission("<html>Something else</html>"), None);
+        assert_eq!(parse_wait("you have 42s left to wait"), Some(Duration::from_secs(42)));
+    }
+}
@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The states of a sequence `s0, step(s0), step(step(s0)), ..` up to its first repeat. From step `start` onwards the
+/// sequence repeats every `length` steps.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cycle<S> {
+    pub start: usize,
+    pub length: usize,
+    /// The states of the steps before the first repeat, `start + length` in total
+    pub states: Vec<S>,
+}
+
+impl<S> Cycle<S> {
+    /// The earliest step with the same state as the given step.
+    pub fn equivalent_step(&self, step: usize) -> usize {
+        if step < self.start {
+            step
+        } else {
+            self.start + (step - self.start) % self.length
+        }
+    }
+
+    /// The state after any number of steps.
+    pub fn at(&self, step: usize) -> &S {
+        &self.states[self.equivalent_step(step)]
+    }
+
+    /// Keeps a value derived from every state instead of the state itself.
+    pub fn map<V>(self, f: impl FnMut(S) -> V) -> Cycle<V> {
+        Cycle { start: self.start, length: self.length, states: self.states.into_iter().map(f).collect() }
+    }
+}
+
+/// Runs `step` until a state repeats and remembers every state on the way. Never returns when the sequence does not
+/// repeat, which can only happen with an infinite number of states.
+pub fn find_cycle<S: Clone + Eq + Hash>(initial: S, step: impl FnMut(&S) -> S) -> Cycle<S> {
+    match run(initial, step, usize::MAX) {
+        Ok(cycle) => cycle,
+        Err(_) => unreachable!("a cycle is found before usize::MAX steps"),
+    }
+}
+
+/// The state after the given number of steps, skipping the repeats once a cycle is found.
+pub fn state_after<S: Clone + Eq + Hash>(initial: S, step: impl FnMut(&S) -> S, steps: usize) -> S {
+    match run(initial, step, steps) {
+        Ok(cycle) => cycle.at(steps).clone(),
+        Err(state) => state,
+    }
+}
+
+/// Finds the cycle, or gives the state after `limit` steps when no state has repeated before that.
+fn run<S: Clone + Eq + Hash>(initial: S, mut step: impl FnMut(&S) -> S, limit: usize) -> Result<Cycle<S>, S> {
+    let mut first_seen: HashMap<S, usize> = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = initial;
+
+    for i in 0..limit {
+        if let Some(&start) = first_seen.get(&state) {
+            return Ok(Cycle { start, length: i - start, states });
+        }
+
+        let next = step(&state);
+        first_seen.insert(state.clone(), i);
+        states.push(state);
+        state = next;
+    }
+
+    Err(state)
+}
+
+/// Brent's algorithm, finds the `(start, length)` of the cycle while keeping only two states in memory, at the cost
+/// of running `step` a few times more often.
+pub fn brent<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> (usize, usize) {
+    // Find the length by moving the tortoise to the hare at every power of two
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // With the hare a cycle length ahead, both meet at the start of the cycle
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    (start, length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 3, 10, 5, 16, 8, 4, 2, 1, 4, 2, 1, ..
+    fn collatz(&n: &u64) -> u64 {
+        if n % 2 == 0 { n / 2 } else { 3 * n + 1 }
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let cycle = find_cycle(3, collatz);
+
+        assert_eq!((cycle.start, cycle.length), (5, 3));
+        assert_eq!(cycle.states, vec![3, 10, 5, 16, 8, 4, 2, 1]);
+        assert_eq!(*cycle.at(1_000_000_000), 1);
+        assert!(*cycle.map(|n| n % 2 == 0).at(1_000_000_001));
+    }
+
+    #[test]
+    fn test_state_after() {
+        assert_eq!(state_after(3, collatz, 3), 16);
+        assert_eq!(state_after(3, collatz, 1_000_000_000), 1);
+    }
+
+    #[test]
+    fn test_brent() {
+        assert_eq!(brent(3, collatz), (5, 3));
+        assert_eq!(brent(0u8, |n| (n + 1) % 7), (0, 7));
+    }
+}
@@ -1,17 +1,34 @@
+use crate::parse::{ParseError, Parser};
+use crate::solution::{Solution, SolveError};
 
+pub struct Day0;
 
-fn main() {
-    let input = parse(include_str!("../../data/day0/input.txt"));
+impl Solution for Day0 {
+    const DAY: u8 = 0;
 
-    println!("Puzzle 1: {}", puzzle_1(&input));
-    println!("Puzzle 2: {}", puzzle_2(&input));
+    type Input<'a> = Vec<&'a str>;
+    type Output = i32;
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(raw_input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<i32, SolveError> {
+        Ok(puzzle_1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Option<i32>, SolveError> {
+        Ok(Some(puzzle_2(input)))
+    }
 }
 
-fn parse(raw_input: &str) -> Vec<&str>
+fn parse(raw_input: &str) -> Result<Vec<&str>, ParseError>
 {
-    raw_input
+    let parser = Parser::new(raw_input);
+
+    Ok(parser
         .lines()
-        .collect()
+        .collect())
 }
 
 fn puzzle_1(_data: &[&str]) -> i32 {
@@ -28,28 +45,28 @@ mod tests {
 
     #[test]
     fn test_puzzle_1_example() {
-        let input = parse(include_str!("../../data/day0/input_example.txt"));
+        let input = parse(include_str!("../../data/day0/input_example.txt")).unwrap();
 
         assert_eq!(puzzle_1(&input), 0);
     }
 
     #[test]
     fn test_puzzle_1() {
-        let input = parse(include_str!("../../data/day0/input.txt"));
+        let input = parse(include_str!("../../data/day0/input.txt")).unwrap();
 
         assert_eq!(puzzle_1(&input), 0);
     }
 
     #[test]
     fn test_puzzle_2_example() {
-        let input = parse(include_str!("../../data/day0/input_example.txt"));
+        let input = parse(include_str!("../../data/day0/input_example.txt")).unwrap();
 
         assert_eq!(puzzle_2(&input), 0);
     }
 
     #[test]
     fn test_puzzle_2() {
-        let input = parse(include_str!("../../data/day0/input.txt"));
+        let input = parse(include_str!("../../data/day0/input.txt")).unwrap();
 
         assert_eq!(puzzle_2(&input), 0);
     }
@@ -1,15 +1,31 @@
 use regex::Regex;
+use crate::parse::ParseError;
+use crate::solution::{Solution, SolveError};
 
-fn main() {
-    let input = parse(include_str!("../../data/day1/input.txt"));
+pub struct Day1;
 
-    puzzle_1(input);
-    puzzle_2(input);
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input<'a> = &'a str;
+    type Output = i32;
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(raw_input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<i32, SolveError> {
+        Ok(puzzle_1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Option<i32>, SolveError> {
+        Ok(Some(puzzle_2(input)))
+    }
 }
 
-fn parse(raw_input: &str) -> &str
+fn parse(raw_input: &str) -> Result<&str, ParseError>
 {
-    raw_input
+    Ok(raw_input)
 }
 
 fn puzzle_1(data: &str) -> i32 {
@@ -60,28 +76,28 @@ mod tests {
 
     #[test]
     fn test_puzzle_1_example() {
-        let input = parse(include_str!("../../data/day1/input_example.txt"));
+        let input = parse(include_str!("../../data/day1/input_example.txt")).unwrap();
 
         assert_eq!(puzzle_1(input), 142i32);
     }
 
     #[test]
     fn test_puzzle_1() {
-        let input = parse(include_str!("../../data/day1/input.txt"));
+        let input = parse(include_str!("../../data/day1/input.txt")).unwrap();
 
         assert_eq!(puzzle_1(input), 54953i32);
     }
 
     #[test]
     fn test_puzzle_2_example() {
-        let input = parse(include_str!("../../data/day1/input_example_2.txt"));
+        let input = parse(include_str!("../../data/day1/input_example_2.txt")).unwrap();
 
         assert_eq!(puzzle_2(input), 281i32);
     }
 
     #[test]
     fn test_puzzle_2() {
-        let input = parse(include_str!("../../data/day1/input.txt"));
+        let input = parse(include_str!("../../data/day1/input.txt")).unwrap();
 
         assert_eq!(puzzle_2(input), 53868i32);
     }
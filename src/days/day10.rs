@@ -0,0 +1,233 @@
+use crate::{Coordinate, Direction};
+use crate::bit_grid::BitGrid;
+use crate::grid::Grid;
+use crate::Direction::{East, North, South, West};
+use crate::image::Rgb;
+use crate::parse::{ParseError, Parser};
+use crate::point::Point;
+use crate::polygon::Polygon;
+use crate::render::{Canvas, Cell};
+use crate::solution::{Part, Solution, SolveError};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input<'a> = LoopMap;
+    type Output = usize;
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(raw_input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<usize, SolveError> {
+        Ok(puzzle_1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Option<usize>, SolveError> {
+        Ok(Some(puzzle_2(input)))
+    }
+
+    fn render(input: &Self::Input<'_>, part: Part) -> Option<Canvas> {
+        Some(render(input, part))
+    }
+}
+
+pub struct LoopMap {
+    map: Grid<char>,
+    start: Coordinate,
+}
+
+struct Branch {
+    coord: Coordinate,
+    path_length: usize,
+    intersection_count: usize,
+}
+
+fn parse(raw_input: &str) -> Result<LoopMap, ParseError> {
+    let parser = Parser::new(raw_input);
+    let map = parser.char_grid(raw_input, "|-LJ7F.S")?;
+
+    let start = map
+        .position(|&c| c == 'S')
+        .ok_or_else(|| parser.error(parser.end(), "a starting tile `S`"))?;
+
+    Ok(LoopMap {
+        map,
+        start,
+    })
+}
+
+fn puzzle_1(loop_map: &LoopMap) -> usize {
+    find_longest_loop(loop_map).len() / 2
+}
+
+fn loop_tiles(map: &Grid<char>, longest_loop: &[Coordinate]) -> BitGrid {
+    let mut on_loop = BitGrid::new(map.height(), map.width());
+    longest_loop.iter().for_each(|&c| { on_loop.insert(c); });
+
+    on_loop
+}
+
+fn puzzle_2(loop_map: &LoopMap) -> usize {
+    // The enclosed tiles are the grid points inside the polygon
+    loop_polygon(loop_map).interior_points() as usize
+}
+
+// The loop as a polygon with a corner at every bend, the straight pipes lie on its edges
+fn loop_polygon(loop_map: &LoopMap) -> Polygon {
+    Polygon::new(
+        find_longest_loop(loop_map)
+            .into_iter()
+            .filter(|&c| !matches!(loop_map.map[c], '|' | '-'))
+            .map(Point::from)
+            .collect()
+    )
+}
+
+// The loop with the tile farthest from the start in the first part, and the tiles it encloses in the second
+fn render(loop_map: &LoopMap, part: Part) -> Canvas {
+    let map = &loop_map.map;
+
+    let longest_loop = find_longest_loop(loop_map);
+    let canvas = Canvas::from_grid(map, |&c| Cell::new(c, Rgb::DARK_GRAY))
+        .with_highlight(loop_tiles(map, &longest_loop).ones(), Rgb::BLUE)
+        .with_legend(Rgb::BLUE, "loop");
+
+    if part == 1 {
+        // Halfway around the loop, the same number of steps from the start both ways
+        let distance = longest_loop.len() / 2;
+
+        canvas
+            .with_highlight(longest_loop.get(distance).copied(), Rgb::RED)
+            .with_legend(Rgb::RED, &format!("farthest tile, {distance} steps"))
+    } else {
+        let polygon = loop_polygon(loop_map);
+        let inside: Vec<Coordinate> = map.iter().map(|(c, _)| c).filter(|&c| polygon.contains(Point::from(c))).collect();
+
+        canvas
+            .with_legend(Rgb::GREEN, &format!("enclosed, {} tiles", inside.len()))
+            .with_highlight(inside, Rgb::GREEN)
+    }
+}
+
+fn find_longest_loop(loop_map: &LoopMap) -> Vec<Coordinate> {
+    let mut longest_path: Vec<Coordinate> = Vec::new();
+    let mut path: Vec<Coordinate> = Vec::from([loop_map.start]);
+    let mut intersections: Vec<Coordinate> = Vec::new();
+    let mut branches: Vec<Branch> = loop_map.map
+        .von_neumann_compass(loop_map.start)
+        .into_iter()
+        .filter(|&(_, c)| loop_map.map[c] != '.')
+        .map(|(_, c)| Branch { coord: c, path_length: 1, intersection_count: 0 })
+        .collect();
+
+    'branch_loop: while let Some(branch) = branches.pop() {
+        let mut current_coord = branch.coord;
+        path.truncate(branch.path_length);
+        intersections.truncate(branch.intersection_count);
+
+        while current_coord != loop_map.start {
+            let current_sign = loop_map.map[current_coord];
+
+            let mut connecting_pipes: Vec<Coordinate> = loop_map.map
+                .von_neumann_compass(current_coord)
+                .into_iter()
+                .filter_map(|(direction, next_coord)| {
+                    let next_sign = loop_map.map[next_coord];
+
+                    if next_sign == '.' || next_coord == path[path.len() -1] || intersections.contains(&next_coord) {
+                        return None;
+                    }
+
+                    pipes_connect(current_sign, next_sign, direction).then_some(next_coord)
+                })
+                .collect();
+
+            path.push(current_coord);
+
+            if connecting_pipes.len() > 1 {
+                intersections.push(current_coord);
+                connecting_pipes
+                    .drain(1..)
+                    .for_each(|c| branches.push(Branch {
+                        coord: c,
+                        path_length: path.len(),
+                        intersection_count: intersections.len(),
+                    }));
+            }
+
+            current_coord = match connecting_pipes.pop() {
+                Some(c) => c,
+                None => continue 'branch_loop,
+            };
+        }
+
+        if path.len() > longest_path.len() {
+            longest_path = path.clone();
+        }
+    }
+
+    longest_path
+}
+
+// Whether the pipes are open towards each other, the start connects to every side
+fn pipes_connect(from: char, to: char, direction: Direction) -> bool {
+    let opens_towards = |sign: char, direction: Direction| match sign {
+        '|' => matches!(direction, North | South),
+        '-' => matches!(direction, East | West),
+        'L' => matches!(direction, North | East),
+        'J' => matches!(direction, North | West),
+        '7' => matches!(direction, South | West),
+        'F' => matches!(direction, South | East),
+        'S' => true,
+        _ => false,
+    };
+
+    opens_towards(from, direction) && opens_towards(to, direction.opposite())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle_1_example() {
+        let input = parse(include_str!("../../data/day10/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), 8);
+    }
+
+    #[test]
+    fn test_puzzle_1_dead_end_at_start() {
+        let input = parse(include_str!("../../data/day10/input_example_5.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), 3);
+    }
+
+    #[test]
+    fn test_puzzle_1() {
+        let input = parse(include_str!("../../data/day10/input.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), 6717);
+    }
+
+    #[test]
+    fn test_puzzle_2_example() {
+        let input1 = parse(include_str!("../../data/day10/input_example_2.txt")).unwrap();
+        let input2 = parse(include_str!("../../data/day10/input_example_3.txt")).unwrap();
+        let input3 = parse(include_str!("../../data/day10/input_example_4.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input1), 4);
+        assert_eq!(puzzle_2(&input2), 8);
+        assert_eq!(puzzle_2(&input3), 10);
+    }
+
+    #[test]
+    fn test_puzzle_2() {
+        let input = parse(include_str!("../../data/day10/input.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), 381);
+    }
+}
\ No newline at end of file
@@ -1,17 +1,36 @@
 use itertools::Itertools;
+use crate::parse::{ParseError, Parser};
+use crate::solution::{Solution, SolveError};
 
-fn main() {
-    let input = parse(include_str!("../../data/day11/input.txt"));
+pub struct Day11;
 
-    println!("Puzzle 1: {}", puzzle_1(&input));
-    println!("Puzzle 2: {}", puzzle_2(&input, 1000000));
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input<'a> = Vec<&'a str>;
+    type Output = usize;
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(raw_input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<usize, SolveError> {
+        Ok(puzzle_1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Option<usize>, SolveError> {
+        Ok(Some(puzzle_2(input, 1000000)))
+    }
 }
 
-fn parse(raw_input: &str) -> Vec<&str>
+fn parse(raw_input: &str) -> Result<Vec<&str>, ParseError>
 {
-    raw_input
+    let parser = Parser::new(raw_input);
+    parser.char_grid(raw_input, ".#")?;
+
+    Ok(parser
         .lines()
-        .collect()
+        .collect())
 }
 
 fn puzzle_1(data: &[&str]) -> usize {
@@ -74,21 +93,21 @@ mod tests {
 
     #[test]
     fn test_puzzle_1_example() {
-        let input = parse(include_str!("../../data/day11/input_example.txt"));
+        let input = parse(include_str!("../../data/day11/input_example.txt")).unwrap();
 
         assert_eq!(puzzle_1(&input), 374);
     }
 
     #[test]
     fn test_puzzle_1() {
-        let input = parse(include_str!("../../data/day11/input.txt"));
+        let input = parse(include_str!("../../data/day11/input.txt")).unwrap();
 
         assert_eq!(puzzle_1(&input), 9556896);
     }
 
     #[test]
     fn test_puzzle_2_example() {
-        let input = parse(include_str!("../../data/day11/input_example.txt"));
+        let input = parse(include_str!("../../data/day11/input_example.txt")).unwrap();
 
         assert_eq!(puzzle_2(&input, 10), 1030);
         assert_eq!(puzzle_2(&input, 100), 8410);
@@ -96,8 +115,13 @@ mod tests {
 
     #[test]
     fn test_puzzle_2() {
-        let input = parse(include_str!("../../data/day11/input.txt"));
+        let input = parse(include_str!("../../data/day11/input.txt")).unwrap();
 
         assert_eq!(puzzle_2(&input, 1000000), 685038186836);
     }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("").is_err());
+    }
 }
\ No newline at end of file
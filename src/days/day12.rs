@@ -1,31 +1,57 @@
 use std::collections::{HashMap};
+use crate::parse::{ParseError, Parser};
+use crate::solution::{Solution, SolveError};
 
-fn main() {
-    let input1 = parse(include_str!("../../data/day12/input.txt"), 1);
-    let input2 = parse(include_str!("../../data/day12/input.txt"), 5);
+pub struct Day12;
 
-    puzzle_1(&input1);
-    puzzle_2(&input2);
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input<'a> = Vec<ConditionRecord>;
+    type Output = u64;
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(raw_input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<u64, SolveError> {
+        Ok(puzzle_1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Option<u64>, SolveError> {
+        Ok(Some(puzzle_2(input)))
+    }
 }
 
-struct ConditionRecord {
+pub struct ConditionRecord {
     springs: Vec<char>,
     groups: Vec<usize>,
 }
 
 type Memo = HashMap<(usize, usize), u64>;
 
-fn parse(raw_input: &str, repeat: usize) -> Vec<ConditionRecord>
+impl ConditionRecord {
+    fn unfold(&self, repeat: usize) -> Self {
+        Self {
+            springs: vec![self.springs.clone(); repeat].join(&'?'),
+            groups: self.groups.repeat(repeat),
+        }
+    }
+}
+
+fn parse(raw_input: &str) -> Result<Vec<ConditionRecord>, ParseError>
 {
-    raw_input
+    let parser = Parser::new(raw_input);
+
+    parser
         .lines()
         .map(|l| {
-            let (left, right) = l.split_once(' ').unwrap();
+            let (left, right) = parser.split_once(l, " ")?;
 
-            ConditionRecord {
-                springs: vec![left; repeat].join("?").chars().collect(),
-                groups: vec![right; repeat].join(",").split(',').map(|count| count.parse::<usize>().unwrap()).collect(),
-            }
+            Ok(ConditionRecord {
+                springs: parser.char_grid(left, ".#?")?.row(0).to_vec(),
+                groups: right.split(',').map(|count| parser.number::<usize>(count)).collect::<Result<_, _>>()?,
+            })
         })
         .collect()
 }
@@ -40,7 +66,7 @@ fn puzzle_1(data: &[ConditionRecord]) -> u64 {
 fn puzzle_2(data: &[ConditionRecord]) -> u64 {
     data
         .iter()
-        .map(count_possible_arrangements)
+        .map(|record| count_possible_arrangements(&record.unfold(5)))
         .sum()
 }
 
@@ -117,28 +143,28 @@ mod tests {
 
     #[test]
     fn test_puzzle_1_example() {
-        let input = parse(include_str!("../../data/day12/input_example.txt"), 1);
+        let input = parse(include_str!("../../data/day12/input_example.txt")).unwrap();
 
         assert_eq!(puzzle_1(&input), 21);
     }
 
     #[test]
     fn test_puzzle_1() {
-        let input = parse(include_str!("../../data/day12/input.txt"), 1);
+        let input = parse(include_str!("../../data/day12/input.txt")).unwrap();
 
         assert_eq!(puzzle_1(&input), 6981);
     }
 
     #[test]
     fn test_puzzle_2_example() {
-        let input = parse(include_str!("../../data/day12/input_example.txt"), 5);
+        let input = parse(include_str!("../../data/day12/input_example.txt")).unwrap();
 
         assert_eq!(puzzle_2(&input), 525152);
     }
 
     #[test]
     fn test_puzzle_2() {
-        let input = parse(include_str!("../../data/day12/input.txt"), 5);
+        let input = parse(include_str!("../../data/day12/input.txt")).unwrap();
 
         assert_eq!(puzzle_2(&input), 4546215031609);
     }
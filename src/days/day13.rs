@@ -1,20 +1,39 @@
 use std::cmp::min;
-use advent_of_code_2023::{count_different_elements, transpose};
+use crate::count_different_elements;
+use crate::grid::Grid;
+use crate::parse::{ParseError, Parser};
+use crate::solution::{Solution, SolveError};
 
-type Pattern = Vec<Vec<char>>;
+type Pattern = Grid<char>;
 
-fn main() {
-    let input = parse(include_str!("../../data/day13/input.txt"));
+pub struct Day13;
 
-    puzzle_1(&input);
-    puzzle_2(&input);
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input<'a> = Vec<Pattern>;
+    type Output = usize;
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(raw_input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<usize, SolveError> {
+        Ok(puzzle_1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Option<usize>, SolveError> {
+        Ok(Some(puzzle_2(input)))
+    }
 }
 
-fn parse(raw_input: &str) -> Vec<Pattern>
+fn parse(raw_input: &str) -> Result<Vec<Pattern>, ParseError>
 {
+    let parser = Parser::new(raw_input);
+
     raw_input
         .split("\n\n")
-        .map(|block| block.lines().map(|l| l.chars().collect()).collect())
+        .map(|block| parser.char_grid(block, ".#"))
         .collect()
 }
 
@@ -24,7 +43,7 @@ fn puzzle_1(data: &[Pattern]) -> usize {
         .map(|p| {
             match find_mirror_position(p) {
                 Some(position) => position * 100,
-                None => find_mirror_position(&transpose(p)).unwrap_or(0),
+                None => find_mirror_position(&p.transpose()).unwrap_or(0),
             }
         })
         .sum()
@@ -36,24 +55,24 @@ fn puzzle_2(data: &[Pattern]) -> usize {
         .map(|p| {
             match find_mirror_position_with_smudge(p) {
                 Some(position) => position * 100,
-                None => find_mirror_position_with_smudge(&transpose(p)).unwrap_or(0),
+                None => find_mirror_position_with_smudge(&p.transpose()).unwrap_or(0),
             }
         })
         .sum()
 }
 
 fn find_mirror_position(pattern: &Pattern) -> Option<usize> {
-    (1..pattern.len())
-        .find(|&position| (1..=min(position, pattern.len() - position))
-            .all(|offset| pattern[position - offset] == pattern[position + offset - 1])
+    (1..pattern.height())
+        .find(|&position| (1..=min(position, pattern.height() - position))
+            .all(|offset| pattern.row(position - offset) == pattern.row(position + offset - 1))
         )
 }
 
 fn find_mirror_position_with_smudge(pattern: &Pattern) -> Option<usize> {
-    (1..pattern.len())
-        .find(|&position| (1..=min(position, pattern.len() - position))
+    (1..pattern.height())
+        .find(|&position| (1..=min(position, pattern.height() - position))
             .try_fold(false, | found_smudge, offset | {
-                let diff = count_different_elements(&pattern[position - offset], &pattern[position + offset - 1]);
+                let diff = count_different_elements(pattern.row(position - offset), pattern.row(position + offset - 1));
                 match (diff, found_smudge) {
                     (0, _) => Some(found_smudge),
                     (1, false) => Some(true),
@@ -70,28 +89,28 @@ mod tests {
 
     #[test]
     fn test_puzzle_1_example() {
-        let input = parse(include_str!("../../data/day13/input_example.txt"));
+        let input = parse(include_str!("../../data/day13/input_example.txt")).unwrap();
 
         assert_eq!(puzzle_1(&input), 405);
     }
 
     #[test]
     fn test_puzzle_1() {
-        let input = parse(include_str!("../../data/day13/input.txt"));
+        let input = parse(include_str!("../../data/day13/input.txt")).unwrap();
 
         assert_eq!(puzzle_1(&input), 37381);
     }
 
     #[test]
     fn test_puzzle_2_example() {
-        let input = parse(include_str!("../../data/day13/input_example.txt"));
+        let input = parse(include_str!("../../data/day13/input_example.txt")).unwrap();
 
         assert_eq!(puzzle_2(&input), 400);
     }
 
     #[test]
     fn test_puzzle_2() {
-        let input = parse(include_str!("../../data/day13/input.txt"));
+        let input = parse(include_str!("../../data/day13/input.txt")).unwrap();
 
         assert_eq!(puzzle_2(&input), 28210);
     }
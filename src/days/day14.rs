@@ -0,0 +1,189 @@
+use log::info;
+use crate::animation::Recorder;
+use crate::cycle::find_cycle;
+use crate::grid::{Grid, GridView, Transform};
+use crate::image::Rgb;
+use crate::parse::{ParseError, Parser};
+use crate::render::{Canvas, Cell};
+use crate::solution::{Part, Solution, SolveError};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input<'a> = Grid<char>;
+    type Output = usize;
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(raw_input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<usize, SolveError> {
+        Ok(puzzle_1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Option<usize>, SolveError> {
+        Ok(Some(puzzle_2(input)))
+    }
+
+    fn animate(input: &Self::Input<'_>, part: Part) -> Option<Recorder> {
+        Some(animate(input, part))
+    }
+}
+
+fn parse(raw_input: &str) -> Result<Grid<char>, ParseError>
+{
+    let parser = Parser::new(raw_input);
+
+    parser.char_grid(raw_input, ".#O")
+}
+
+fn puzzle_1(data: &Grid<char>) -> usize {
+    let mut platform = data.clone();
+    platform.apply(Transform::Rotate270); // North -> West
+    tilt_platform(&mut platform, |_| {});
+
+    calculate_load(&platform)
+}
+
+fn puzzle_2(data: &Grid<char>) -> usize {
+    let mut platform = data.clone();
+    platform.apply(Transform::Rotate270); // North -> West
+
+    let loads = find_cycle(platform, |platform| {
+        let mut platform = platform.clone();
+        cycle_platform(&mut platform);
+        platform
+    }).map(|platform| calculate_load(&platform));
+
+    info!("Found a cycle of {} spin cycles starting after spin cycle {}", loads.length, loads.start);
+
+    *loads.at(1000000000)
+}
+
+// Every step of the rocks rolling north in the first part, and the platform after every tilt of the spin cycles
+// up to the first repeat in the second
+fn animate(data: &Grid<char>, part: Part) -> Recorder {
+    let draw = |platform: GridView<char>| {
+        Canvas::from_grid(&platform.to_grid(), |&c| match c {
+            'O' => Cell::new(c, Rgb::ORANGE),
+            '#' => Cell::new(c, Rgb::GRAY),
+            _ => Cell::new(c, Rgb::DARK_GRAY),
+        })
+    };
+
+    let mut recorder = Recorder::new();
+    recorder.record(&draw(data.view(Transform::Identity)));
+
+    let mut platform = data.clone();
+    platform.apply(Transform::Rotate270); // North -> West
+
+    if part == 1 {
+        tilt_platform(&mut platform, |platform| recorder.record(&draw(platform.view(Transform::Rotate90))));
+    } else {
+        let spin_cycles = find_cycle(platform.clone(), |platform| {
+            let mut platform = platform.clone();
+            cycle_platform(&mut platform);
+            platform
+        }).states.len();
+
+        // Turns the platform back after each of the tilts north, west, south and east
+        let upright = [Transform::Rotate90, Transform::Identity, Transform::Rotate270, Transform::Rotate180];
+
+        for _ in 0..spin_cycles {
+            for transform in upright {
+                tilt_platform(&mut platform, |_| {});
+                recorder.record(&draw(platform.view(transform)));
+                platform.apply(Transform::Rotate90);
+            }
+        }
+    }
+
+    recorder
+}
+
+// Tilts north, west, south and east, turning the platform so each of those in turn faces west
+fn cycle_platform(platform: &mut Grid<char>) {
+    for _ in 0..4 {
+        tilt_platform(platform, |_| {});
+        platform.apply(Transform::Rotate90);
+    }
+}
+
+// Rolls the rounded rocks west, every rock with free space in front of it moves one step at a time until none can move
+fn tilt_platform(platform: &mut Grid<char>, mut on_step: impl FnMut(&Grid<char>)) {
+    loop {
+        let mut moved = false;
+
+        for line in platform.rows_mut() {
+            let mut x = 1;
+
+            while x < line.len() {
+                if line[x - 1] == '.' && line[x] == 'O' {
+                    line.swap(x - 1, x);
+                    moved = true;
+
+                    // The space the rock leaves is only free from the next step on
+                    x += 1;
+                }
+
+                x += 1;
+            }
+        }
+
+        if !moved {
+            return;
+        }
+
+        on_step(platform);
+    }
+}
+
+fn calculate_load(platform: &Grid<char>) -> usize {
+    platform
+        .rows()
+        .map(|line| line
+            .iter()
+            .zip((1..=platform.width()).rev())
+            .filter_map(|(&c, i)| match c {
+                'O' => Some(i),
+                _ => None,
+            })
+            .sum::<usize>()
+        )
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle_1_example() {
+        let input = parse(include_str!("../../data/day14/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), 136);
+    }
+
+    #[test]
+    fn test_puzzle_1() {
+        let input = parse(include_str!("../../data/day14/input.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), 110274);
+    }
+
+    #[test]
+    fn test_puzzle_2_example() {
+        let input = parse(include_str!("../../data/day14/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), 64);
+    }
+
+    #[test]
+    fn test_puzzle_2() {
+        let input = parse(include_str!("../../data/day14/input.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), 90982);
+    }
+}
\ No newline at end of file
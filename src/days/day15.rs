@@ -1,3 +1,6 @@
+use crate::parse::{ParseError, Parser};
+use crate::solution::{Solution, SolveError};
+
 #[derive(Clone, Default)]
 struct LensBox {
     lenses: Vec<(String, usize)>,
@@ -23,37 +26,71 @@ impl LensBox {
     }
 }
 
-fn main() {
-    let input = parse(include_str!("../../data/day15/input.txt"));
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input<'a> = Vec<Step<'a>>;
+    type Output = usize;
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(raw_input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<usize, SolveError> {
+        Ok(puzzle_1(input))
+    }
 
-    println!("Puzzle 1: {}", puzzle_1(&input));
-    println!("Puzzle 2: {}", puzzle_2(&input));
+    fn part2(input: &Self::Input<'_>) -> Result<Option<usize>, SolveError> {
+        Ok(Some(puzzle_2(input)))
+    }
+}
+
+pub struct Step<'a> {
+    text: &'a str,
+    label: &'a str,
+    /// `None` removes the lens with the label
+    focal_length: Option<usize>,
 }
 
-fn parse(raw_input: &str) -> Vec<&str>
+fn parse(raw_input: &str) -> Result<Vec<Step<'_>>, ParseError>
 {
+    let parser = Parser::new(raw_input);
+
     raw_input
         .split(',')
+        .map(|text| {
+            let (label, focal_length) = match text.strip_suffix('-') {
+                Some(label) => (label, None),
+                None => {
+                    let (label, focal_length) = text
+                        .split_once('=')
+                        .ok_or_else(|| parser.error(text, "`-` or `=`"))?;
+
+                    (label, Some(parser.number::<usize>(focal_length)?))
+                },
+            };
+
+            Ok(Step { text, label, focal_length })
+        })
         .collect()
 }
 
-fn puzzle_1(data: &[&str]) -> usize {
+fn puzzle_1(data: &[Step]) -> usize {
     data
         .iter()
-        .map(|&s| hash(s))
+        .map(|s| hash(s.text))
         .sum()
 }
 
-fn puzzle_2(data: &[&str]) -> usize {
+fn puzzle_2(data: &[Step]) -> usize {
     data
         .iter()
-        .fold(vec![Default::default(); 256], |mut boxes: Vec<LensBox>, &step| {
-            if let Some((label, _)) = step.split_once('-') {
-                boxes[hash(label)].remove_lens(label);
-            } else {
-                let (label, focal_length) = step.split_once('=').unwrap();
-
-                boxes[hash(label)].add_lens(label, focal_length.parse::<usize>().unwrap());
+        .fold(vec![Default::default(); 256], |mut boxes: Vec<LensBox>, step| {
+            match step.focal_length {
+                None => boxes[hash(step.label)].remove_lens(step.label),
+                Some(focal_length) => boxes[hash(step.label)].add_lens(step.label, focal_length),
             }
 
             boxes
@@ -82,28 +119,28 @@ mod tests {
 
     #[test]
     fn test_puzzle_1_example() {
-        let input = parse(include_str!("../../data/day15/input_example.txt"));
+        let input = parse(include_str!("../../data/day15/input_example.txt")).unwrap();
 
         assert_eq!(puzzle_1(&input), 1320);
     }
 
     #[test]
     fn test_puzzle_1() {
-        let input = parse(include_str!("../../data/day15/input.txt"));
+        let input = parse(include_str!("../../data/day15/input.txt")).unwrap();
 
         assert_eq!(puzzle_1(&input), 513172);
     }
 
     #[test]
     fn test_puzzle_2_example() {
-        let input = parse(include_str!("../../data/day15/input_example.txt"));
+        let input = parse(include_str!("../../data/day15/input_example.txt")).unwrap();
 
         assert_eq!(puzzle_2(&input), 145);
     }
 
     #[test]
     fn test_puzzle_2() {
-        let input = parse(include_str!("../../data/day15/input.txt"));
+        let input = parse(include_str!("../../data/day15/input.txt")).unwrap();
 
         assert_eq!(puzzle_2(&input), 237806);
     }
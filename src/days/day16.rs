@@ -0,0 +1,380 @@
+use std::collections::HashMap;
+use std::cmp::{min, max};
+use std::mem;
+use crate::{Coordinate, Orthogonal};
+use crate::animation::Recorder;
+use crate::bit_grid::BitGrid;
+use crate::grid::Grid;
+use crate::Orthogonal::{North, East, South, West};
+use crate::image::Rgb;
+use crate::parse::{ParseError, Parser};
+use crate::render::{Canvas, Cell};
+use crate::solution::{Part, Solution, SolveError};
+
+type Contraption = (Grid<char>, HashMap<Coordinate, Reflector>);
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input<'a> = Contraption;
+    type Output = usize;
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(raw_input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<usize, SolveError> {
+        Ok(puzzle_1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Option<usize>, SolveError> {
+        Ok(Some(puzzle_2(input)))
+    }
+
+    fn render(input: &Self::Input<'_>, part: Part) -> Option<Canvas> {
+        Some(render(input, part))
+    }
+
+    fn animate(input: &Self::Input<'_>, part: Part) -> Option<Recorder> {
+        Some(animate(input, part))
+    }
+}
+
+// fn parse(raw_input: &str) -> Vec<Vec<char>> {
+//     raw_input
+//         .lines()
+//         .map(|l| l.chars().collect())
+//         .collect()
+// }
+
+fn parse(raw_input: &str) -> Result<Contraption, ParseError> {
+    let parser = Parser::new(raw_input);
+    let map = parser.char_grid(raw_input, r".-|/\")?;
+
+    let mut reflectors: HashMap<Coordinate, Reflector> = HashMap::new();
+
+    for (coord, &c) in map.iter() {
+        if c != '.' {
+            reflectors.insert(coord, Reflector::from_map(&coord, &map));
+        }
+    }
+
+    Ok((map, reflectors))
+}
+
+enum ReflectorType {
+    Horizontal,
+    Vertical,
+    Angle45,
+    Angle315,
+}
+
+impl ReflectorType {
+    fn from_char(char: char) -> Option<Self> {
+        match char {
+            '-' => Some(ReflectorType::Horizontal),
+            '|' => Some(ReflectorType::Vertical),
+            '/' => Some(ReflectorType::Angle45),
+            '\\' => Some(ReflectorType::Angle315),
+            _ => None,
+        }
+    }
+
+    fn bounce_light(&self, light_movement_direction: &Orthogonal) -> Result<Orthogonal, &'static str> {
+        match self {
+            ReflectorType::Angle45 => match light_movement_direction {
+                North => Ok(East),
+                East => Ok(North),
+                South => Ok(West),
+                West => Ok(South),
+            }
+            ReflectorType::Angle315 => match light_movement_direction {
+                North => Ok(West),
+                East => Ok(South),
+                South => Ok(East),
+                West => Ok(North),
+            }
+            _ => Err("Unexpected reflector type for bouncing"),
+        }
+    }
+
+    fn split_light(&self, light_movement_direction: &Orthogonal) -> Result<Option<(Orthogonal, Orthogonal)>, &'static str> {
+        match self {
+            ReflectorType::Horizontal => Ok(match light_movement_direction {
+                North | South => Some((East, West)),
+                _ => None,
+            }),
+            ReflectorType::Vertical => Ok(match light_movement_direction {
+                East | West => Some((North, South)),
+                _ => None,
+            }),
+            _ => Err("Unexpected reflector type for splitting"),
+        }
+    }
+}
+
+pub struct Reflector {
+    reflector_type: ReflectorType,
+    connections: HashMap<Orthogonal, Coordinate>,
+}
+
+impl Reflector {
+    fn from_map(coord: &Coordinate, map: &Grid<char>) -> Self {
+        let height = map.height();
+        let width = map.width();
+
+        Self {
+            reflector_type: ReflectorType::from_char(map[*coord]).unwrap(),
+            connections: HashMap::from([
+                (North, find_next_reflector(map, coord, &North).unwrap_or((0, coord.1))),
+                (East, find_next_reflector(map, coord, &East).unwrap_or((coord.0, width - 1))),
+                (South, find_next_reflector(map, coord, &South).unwrap_or((height - 1, coord.1))),
+                (West, find_next_reflector(map, coord, &West).unwrap_or((coord.0, 0))),
+            ])
+        }
+    }
+
+    fn next(&self, light_movement_direction: &Orthogonal) -> Vec<(Orthogonal, Coordinate)> {
+        match self.reflector_type {
+            ReflectorType::Horizontal | ReflectorType::Vertical => match self.reflector_type.split_light(light_movement_direction).expect("Could not split light") {
+                Some((d1, d2)) => vec![(d1, self.connections[&d1]), (d2, self.connections[&d2])],
+
+                // Continues in same direction
+                None => vec![(*light_movement_direction, self.connections[light_movement_direction])],
+            },
+            ReflectorType::Angle45 | ReflectorType::Angle315 => {
+                let next_direction = self.reflector_type.bounce_light(light_movement_direction).expect("Could not bounce light");
+                vec![(next_direction, self.connections[&next_direction])]
+            },
+        }
+    }
+}
+
+fn find_next_reflector(map: &Grid<char>, coord: &Coordinate, direction: &Orthogonal) -> Option<Coordinate> {
+    let height = map.height();
+    let width = map.width();
+
+    match direction {
+        North => (0..coord.0).rposition(|row| map[(row, coord.1)] != '.').map(|row| (row, coord.1)),
+        East => ((coord.1 + 1)..width).find(|&col| map[(coord.0, col)] != '.').map(|col| (coord.0, col)),
+        South => ((coord.0 + 1)..height).find(|&row| map[(row, coord.1)] != '.').map(|row| (row, coord.1)),
+        West => map.row(coord.0)[..coord.1].iter().rposition(|&c| c != '.').map(|col| (coord.0, col)),
+    }
+}
+
+// One layer per direction of the light
+struct BeamGrid {
+    layers: [BitGrid; 4],
+}
+
+impl BeamGrid {
+    fn new(height: usize, width: usize) -> Self {
+        Self {
+            layers: std::array::from_fn(|_| BitGrid::new(height, width)),
+        }
+    }
+
+    // Adds beam to grid and returns the number of new beam coordinates that have been added in the given direction
+    fn add_beam(&mut self, direction: &Orthogonal, from: &Coordinate, to: &Coordinate, goes_outside: bool) -> usize {
+        let layer = &mut self.layers[*direction as usize];
+
+        match direction {
+            North|South => (min(from.0, to.0)..=max(from.0, to.0))
+                .filter(|&row| {
+                    if !goes_outside && row == to.0 {
+                        return false;
+                    }
+
+                    layer.insert((row, from.1))
+                })
+                .count(),
+            East|West => (min(from.1, to.1)..=max(from.1, to.1))
+                .filter(|&col| {
+                    if !goes_outside && col == to.1 {
+                        return false;
+                    }
+
+                    layer.insert((from.0, col))
+                })
+                .count(),
+        }
+    }
+
+    fn energized(&self) -> BitGrid {
+        let [north, east, south, west] = &self.layers;
+
+        &(north | east) | &(south | west)
+    }
+}
+
+fn puzzle_1((map, reflectors): &Contraption) -> usize {
+    energize(map, reflectors, &(0, 0), &East, |_| {}).count_ones()
+}
+
+fn puzzle_2((map, reflectors): &Contraption) -> usize {
+    edge_beams(map)
+        .map(|(coord, direction)| energize(map, reflectors, &coord, &direction, |_| {}).count_ones())
+        .max()
+        .unwrap()
+}
+
+// Every beam that can enter from the edge, pointing into the contraption
+fn edge_beams(map: &Grid<char>) -> impl Iterator<Item = (Coordinate, Orthogonal)> {
+    let height = map.height();
+    let width = map.width();
+
+    (0..width).map(|col| ((0, col), South))
+        .chain((0..height).map(|row| ((row, 0), East)))
+        .chain((0..height).map(move |row| ((row, width - 1), West)))
+        .chain((0..width).map(move |col| ((height - 1, col), North)))
+}
+
+// The energized tiles on the contraption, with the beam entering from the edge in the first part and the beam that
+// energizes the most tiles in the second
+fn render((map, reflectors): &Contraption, part: Part) -> Canvas {
+    let (start_coord, start_direction) = entry_beam(map, reflectors, part);
+    let energized = energize(map, reflectors, &start_coord, &start_direction, |_| {});
+
+    draw(map, &energized, start_coord)
+}
+
+// The beams spreading through the contraption, a frame for every time they all move on to the next reflector
+fn animate((map, reflectors): &Contraption, part: Part) -> Recorder {
+    let (start_coord, start_direction) = entry_beam(map, reflectors, part);
+    let mut recorder = Recorder::new();
+
+    energize(map, reflectors, &start_coord, &start_direction, |beam_grid| {
+        recorder.record(&draw(map, &beam_grid.energized(), start_coord));
+    });
+
+    recorder
+}
+
+fn entry_beam(map: &Grid<char>, reflectors: &HashMap<Coordinate, Reflector>, part: Part) -> (Coordinate, Orthogonal) {
+    if part == 1 {
+        ((0, 0), East)
+    } else {
+        edge_beams(map)
+            .max_by_key(|(coord, direction)| energize(map, reflectors, coord, direction, |_| {}).count_ones())
+            .unwrap()
+    }
+}
+
+fn draw(map: &Grid<char>, energized: &BitGrid, start_coord: Coordinate) -> Canvas {
+    Canvas::from_grid(map, |&c| Cell::new(c, if c == '.' { Rgb::DARK_GRAY } else { Rgb::WHITE }))
+        .with_highlight(energized.ones(), Rgb::YELLOW)
+        .with_highlight([start_coord], Rgb::RED)
+        .with_legend(Rgb::YELLOW, &format!("energized, {} tiles", energized.count_ones()))
+        .with_legend(Rgb::RED, "entry")
+}
+
+// Follows the beams from reflector to reflector, `on_step` sees the beams after each time all of them have moved on
+fn energize(
+    map: &Grid<char>,
+    reflectors: &HashMap<Coordinate, Reflector>,
+    start_coord: &Coordinate,
+    start_direction: &Orthogonal,
+    mut on_step: impl FnMut(&BeamGrid),
+) -> BitGrid {
+    let height = map.height();
+    let width = map.width();
+    let mut beam_grid: BeamGrid = BeamGrid::new(height, width);
+    let mut beams: Vec<(Orthogonal, Coordinate)> = Vec::new();
+
+    if let Some(first_reflector_coord) = find_first_reflector(map, start_coord, start_direction) {
+        beam_grid.add_beam(start_direction, start_coord, &first_reflector_coord, false);
+        beams.push((*start_direction, first_reflector_coord));
+    } else {
+        match start_direction {
+            North => beam_grid.add_beam(start_direction, start_coord, &(0, start_coord.1), true),
+            East => beam_grid.add_beam(start_direction, start_coord, &(start_coord.0, width - 1), true),
+            South => beam_grid.add_beam(start_direction, start_coord, &(height - 1, start_coord.1), true),
+            West => beam_grid.add_beam(start_direction, start_coord, &(start_coord.0, 0), true),
+        };
+        on_step(&beam_grid);
+
+        return beam_grid.energized();
+    }
+
+    on_step(&beam_grid);
+
+    while !beams.is_empty() {
+        for (direction, coord) in mem::take(&mut beams) {
+            if let Some(reflector) = reflectors.get(&coord) {
+                for (next_direction, next_coord) in reflector.next(&direction) {
+                    let goes_outside = coord == next_coord || !reflectors.contains_key(&next_coord);
+                    let new_beam_size = beam_grid.add_beam(&next_direction, &coord, &next_coord, goes_outside);
+                    if !goes_outside && new_beam_size > 0 {
+                        beams.push((next_direction, next_coord))
+                    }
+                }
+            }
+        }
+
+        on_step(&beam_grid);
+    }
+
+    beam_grid.energized()
+}
+
+fn find_first_reflector(map: &Grid<char>, coord: &Coordinate, direction: &Orthogonal) -> Option<Coordinate> {
+    let height = map.height();
+    let width = map.width();
+
+    match direction {
+        North => (0..coord.0 + 1).rposition(|row| map[(row, coord.1)] != '.').map(|row| (row, coord.1)),
+        East => (coord.1..width).find(|&col| map[(coord.0, col)] != '.').map(|col| (coord.0, col)),
+        South => (coord.0..height).find(|&row| map[(row, coord.1)] != '.').map(|row| (row, coord.1)),
+        West => map.row(coord.0)[..=coord.1].iter().rposition(|&c| c != '.').map(|col| (coord.0, col)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle_1_example() {
+        let input = parse(include_str!("../../data/day16/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), 46);
+    }
+
+    #[test]
+    fn test_puzzle_1() {
+        let input = parse(include_str!("../../data/day16/input.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), 6816);
+    }
+
+    #[test]
+    fn test_puzzle_2_example() {
+        let input = parse(include_str!("../../data/day16/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), 51);
+    }
+
+    #[test]
+    fn test_puzzle_2() {
+        let input = parse(include_str!("../../data/day16/input.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), 8163);
+    }
+
+    #[test]
+    fn test_energize_towards_north() {
+        // Wider than high, and a splitter on the tile the beam enters
+        let (map, reflectors) = parse(".....\n.....").unwrap();
+        assert_eq!(energize(&map, &reflectors, &(1, 3), &North, |_| {}).count_ones(), 2);
+
+        let (map, reflectors) = parse("..\n..\n-.").unwrap();
+        assert_eq!(energize(&map, &reflectors, &(2, 0), &North, |_| {}).count_ones(), 2);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("").is_err());
+    }
+}
\ No newline at end of file
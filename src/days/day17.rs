@@ -0,0 +1,180 @@
+use crate::Direction;
+use crate::grid::Grid;
+use crate::Direction::{East, North, South, West};
+use crate::image::Rgb;
+use crate::parse::{ParseError, Parser};
+use crate::render::{Canvas, Cell};
+use crate::shortest_path::{a_star, ShortestPath};
+use crate::solution::{Part, Solution, SolveError};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input<'a> = Grid<usize>;
+    type Output = usize;
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(raw_input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<usize, SolveError> {
+        puzzle_1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Option<usize>, SolveError> {
+        puzzle_2(input).map(Some)
+    }
+
+    fn render(input: &Self::Input<'_>, part: Part) -> Option<Canvas> {
+        render(input, part)
+    }
+}
+
+fn parse(raw_input: &str) -> Result<Grid<usize>, ParseError> {
+    let parser = Parser::new(raw_input);
+
+    parser.grid(raw_input, "a digit", |c| c.to_digit(10).map(|d| d as usize))
+}
+
+fn puzzle_1(data: &Grid<usize>) -> Result<usize, SolveError> {
+    let graph = build_graph(data, 1, 3);
+
+    find_shortest_path(data, &graph, 0, graph.len() - 1)
+        .map(|route| route.cost)
+        .ok_or_else(|| SolveError::new("the crucible can not reach the bottom right block"))
+}
+
+fn puzzle_2(data: &Grid<usize>) -> Result<usize, SolveError> {
+    let graph = build_graph(data, 4, 10);
+
+    find_shortest_path(data, &graph, 0, graph.len() - 1)
+        .map(|route| route.cost)
+        .ok_or_else(|| SolveError::new("the ultra crucible can not reach the bottom right block"))
+}
+
+// The blocks from light to dark by heat loss, with the route of the crucible
+fn render(data: &Grid<usize>, part: Part) -> Option<Canvas> {
+    let graph = if part == 1 { build_graph(data, 1, 3) } else { build_graph(data, 4, 10) };
+    let route = find_shortest_path(data, &graph, 0, graph.len() - 1)?;
+    let corners: Vec<_> = route.path.iter().map(|&(position, _)| (position / data.width(), position % data.width())).collect();
+
+    let canvas = Canvas::from_grid(data, |&heat_loss| {
+        Cell::new(char::from_digit(heat_loss as u32, 10).unwrap(), Rgb::WHITE.mix(Rgb::DARK_GRAY, heat_loss as f64 / 9.0))
+    })
+        .with_path(&corners, Rgb::RED)
+        .with_legend(Rgb::RED, &format!("route, heat loss {}", route.cost));
+
+    Some(canvas)
+}
+
+fn build_graph(data: &Grid<usize>, min_steps: usize, max_steps: usize) -> Vec<Vec<Edge>> {
+    let width = data.width();
+    let mut graph = Vec::with_capacity(width * data.height());
+
+    for i in 0..data.height() {
+        for j in 0..width {
+            let mut edges = Vec::new();
+
+            for direction in [North, East, South, West] {
+                let mut cost_sum = 0;
+                for offset in 1..=max_steps {
+                    if let Some((y, x)) = data.coordinate_steps_in_direction((i, j), offset, direction) {
+                        cost_sum += data[(y, x)];
+
+                        if offset >= min_steps {
+                            edges.push(Edge { position: y * width + x, cost: cost_sum, direction })
+                        }
+                    } else {
+                        break
+                    }
+                }
+            }
+
+            graph.push(edges);
+        }
+    }
+
+    graph
+}
+
+fn find_shortest_path(data: &Grid<usize>, graph: &[Vec<Edge>], start: usize, end: usize) -> Option<ShortestPath<(usize, Direction), usize>> {
+    // Every move has to turn, so starting as if coming from the west or north allows the first move to go anywhere
+    let starts = [(start, East), (start, South)];
+
+    let neighbors = |&(position, direction): &(usize, Direction)| graph[position]
+        .iter()
+        .filter(move |edge| edge.direction != direction && edge.direction != direction.opposite())
+        .map(|edge| ((edge.position, edge.direction), edge.cost));
+
+    // Every block on the way to the end costs at least the least heat loss of any block, with blocks that cost
+    // nothing this is 0 and the search is plain Dijkstra
+    let width = data.width();
+    let least_heat_loss = data.iter().map(|(_, &heat_loss)| heat_loss).min().unwrap_or(0);
+    let heuristic = |&(position, _): &(usize, Direction)| {
+        ((end / width).abs_diff(position / width) + (end % width).abs_diff(position % width)) * least_heat_loss
+    };
+
+    a_star(starts, neighbors, heuristic, |&(position, _)| position == end)
+}
+
+struct Edge {
+    position: usize,
+    cost: usize,
+    direction: Direction,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle_1_example() {
+        let input = parse(include_str!("../../data/day17/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), Ok(102));
+    }
+
+    #[test]
+    fn test_puzzle_1() {
+        let input = parse(include_str!("../../data/day17/input.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), Ok(1004));
+    }
+
+    #[test]
+    fn test_puzzle_2_example() {
+        let input = parse(include_str!("../../data/day17/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), Ok(94));
+    }
+
+    #[test]
+    fn test_puzzle_2() {
+        let input = parse(include_str!("../../data/day17/input.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), Ok(1171));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("").is_err());
+        assert_eq!(parse("19\n0x").unwrap_err().expected, "a digit");
+    }
+
+    #[test]
+    fn test_free_blocks() {
+        let input = parse("1999\n0999\n0000").unwrap();
+
+        assert_eq!(puzzle_1(&input), Ok(0));
+    }
+
+    #[test]
+    fn test_no_route() {
+        let input = parse("11\n11").unwrap();
+
+        assert_eq!(puzzle_1(&input), Ok(2));
+        assert!(puzzle_2(&input).is_err());
+    }
+}
\ No newline at end of file
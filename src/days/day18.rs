@@ -0,0 +1,138 @@
+use crate::Orthogonal;
+use crate::parse::{ParseError, Parser};
+use crate::point::Point;
+use crate::polygon::Polygon;
+use crate::solution::{Solution, SolveError};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input<'a> = DigPlan;
+    type Output = i64;
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(raw_input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<i64, SolveError> {
+        Ok(puzzle_1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Option<i64>, SolveError> {
+        Ok(Some(puzzle_2(input)))
+    }
+}
+
+#[derive(Copy, Clone)]
+struct Instruction {
+    direction: Orthogonal,
+    size: i64,
+}
+
+pub struct DigPlan {
+    instructions: Vec<Instruction>,
+    hex_instructions: Vec<Instruction>,
+}
+
+fn parse(raw_input: &str) -> Result<DigPlan, ParseError>
+{
+    let parser = Parser::new(raw_input);
+
+    let (instructions, hex_instructions) = parser
+        .lines()
+        .map(|l| {
+            let (direction, rest) = parser.split_once(l, " ")?;
+            let (size, hex) = parser.split_once(rest, " ")?;
+
+            let instruction = Instruction {
+                direction: direction
+                    .parse::<char>()
+                    .ok()
+                    .filter(|c| "UDLR".contains(*c))
+                    .and_then(|c| Orthogonal::try_from(c).ok())
+                    .ok_or_else(|| parser.error(direction, "`U`, `D`, `L` or `R`"))?,
+                size: parser.number::<i64>(size)?,
+            };
+
+            let hex = parser.strip(hex, "(#", ")")?;
+
+            if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(parser.error(hex, "six hexadecimal digits"));
+            }
+
+            let (hex_size, hex_direction) = hex.split_at(5);
+
+            let hex_instruction = Instruction {
+                direction: hex_direction
+                    .parse::<char>()
+                    .ok()
+                    .and_then(Orthogonal::from_digit)
+                    .ok_or_else(|| parser.error(hex_direction, "a direction digit between 0 and 3"))?,
+                size: i64::from_str_radix(hex_size, 16).map_err(|_| parser.error(hex_size, "a hexadecimal number"))?,
+            };
+
+            Ok((instruction, hex_instruction))
+        })
+        .collect::<Result<Vec<(Instruction, Instruction)>, ParseError>>()?
+        .into_iter()
+        .unzip();
+
+    Ok(DigPlan { instructions, hex_instructions })
+}
+
+fn puzzle_1(data: &DigPlan) -> i64 {
+    measure_trench_from_instructions(&data.instructions)
+}
+
+fn puzzle_2(data: &DigPlan) -> i64 {
+    measure_trench_from_instructions(&data.hex_instructions)
+}
+
+fn measure_trench_from_instructions(instructions: &[Instruction]) -> i64 {
+    // The trench is one cube wide around the corners of the lagoon, so every enclosed grid point is dug out
+    Polygon::from_steps(Point::ORIGIN, instructions.iter().map(|i| (i.direction.into(), i.size))).enclosed_points()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle_1_example() {
+        let input = parse(include_str!("../../data/day18/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), 62);
+    }
+
+    #[test]
+    fn test_puzzle_1() {
+        let input = parse(include_str!("../../data/day18/input.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), 49061);
+    }
+
+    #[test]
+    fn test_puzzle_2_example() {
+        let input = parse(include_str!("../../data/day18/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), 952408144115);
+    }
+
+    #[test]
+    fn test_puzzle_2() {
+        let input = parse(include_str!("../../data/day18/input.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), 92556825427032);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse("R 6 (#70c710)\nX 5 (#0dc571)").err().unwrap();
+        assert_eq!((error.line, error.column, error.found.as_str()), (2, 1, "X"));
+
+        let error = parse("R 6 (#70c714)").err().unwrap();
+        assert_eq!((error.line, error.column, error.found.as_str()), (1, 12, "4"));
+    }
+}
\ No newline at end of file
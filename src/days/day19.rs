@@ -0,0 +1,265 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use crate::interval::{Cuboid, Interval};
+use crate::parse::{ParseError, Parser};
+use crate::solution::{Solution, SolveError};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input<'a> = (Workflows, Vec<Categories>);
+    type Output = usize;
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(raw_input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<usize, SolveError> {
+        Ok(puzzle_1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Option<usize>, SolveError> {
+        Ok(Some(puzzle_2(input)))
+    }
+}
+
+type Categories = [usize; 4];
+type CategoryRanges = Cuboid<4>;
+
+struct Rule {
+    category: usize,
+    equation: Ordering,
+    right_value: usize,
+    next_workflow: String,
+}
+
+impl Rule {
+    fn eval(&self, categories: &Categories) -> Option<&str> {
+        let value = categories[self.category];
+
+        if match self.equation {
+            Ordering::Less => value < self.right_value,
+            Ordering::Equal => value == self.right_value,
+            Ordering::Greater => value > self.right_value,
+        } {
+            return Some(&self.next_workflow);
+        }
+
+        None
+    }
+
+    fn eval_range(&self, category_ranges: &CategoryRanges) -> (&str, Option<CategoryRanges>, Option<CategoryRanges>) {
+        let right_value = self.right_value as i64;
+
+        match self.equation {
+            Ordering::Less => {
+                let (true_category_ranges, false_category_ranges) = category_ranges.split_at(self.category, right_value);
+                (&self.next_workflow, true_category_ranges, false_category_ranges)
+            },
+            _ => {
+                let (false_category_ranges, true_category_ranges) = category_ranges.split_at(self.category, right_value + 1);
+                (&self.next_workflow, true_category_ranges, false_category_ranges)
+            },
+        }
+    }
+}
+
+pub struct Workflows {
+    workflow_map: HashMap<String, (Vec<Rule>, String)>
+}
+
+impl Workflows {
+    fn part_is_accepted(&self, categories: &Categories) -> bool {
+        let mut workflow_name = "in";
+
+        'workflow_loop: loop {
+            let (rules, otherwise) = self.workflow_map.get(workflow_name).unwrap();
+
+            for rule in rules {
+                if let Some(new_workflow_name) = rule.eval(categories) {
+                    match new_workflow_name {
+                        "A" => return true,
+                        "R" => return false,
+                        _ => {
+                            workflow_name = new_workflow_name;
+                            continue 'workflow_loop;
+                        },
+                    }
+                }
+            }
+
+            match otherwise.as_str() {
+                "A" => return true,
+                "R" => return false,
+                _ => workflow_name = otherwise,
+            }
+        }
+    }
+
+    fn count_accepted_combinations(&self, workflow_name: &str, start_category_ranges: &CategoryRanges) -> usize {
+        match workflow_name {
+            "A" => start_category_ranges.volume() as usize,
+            "R" => 0,
+            _ => {
+                let mut category_ranges = *start_category_ranges;
+                let mut total_count = 0;
+
+                let (rules, otherwise) = self.workflow_map.get(workflow_name).unwrap();
+
+                for rule in rules {
+                    let (new_workflow_name, true_category_ranges, false_category_ranges) = rule.eval_range(&category_ranges);
+
+                    if let Some(true_category_ranges) = true_category_ranges {
+                        total_count += self.count_accepted_combinations(new_workflow_name, &true_category_ranges);
+                    }
+
+                    if false_category_ranges.is_none() {
+                        return total_count;
+                    }
+
+                    category_ranges = false_category_ranges.unwrap();
+                }
+
+                match otherwise.as_str() {
+                    "A" => total_count + category_ranges.volume() as usize,
+                    "R" => total_count,
+                    _ => total_count + self.count_accepted_combinations(otherwise, &category_ranges),
+                }
+            }
+        }
+    }
+}
+
+fn parse(raw_input: &str) -> Result<(Workflows, Vec<Categories>), ParseError>
+{
+    let parser = Parser::new(raw_input);
+    let (workflows, parts) = parser.split_once(raw_input, "\n\n")?;
+
+    let category = |fragment: &str| match fragment {
+        "x" => Ok(0),
+        "m" => Ok(1),
+        "a" => Ok(2),
+        "s" => Ok(3),
+        _ => Err(parser.error(fragment, "`x`, `m`, `a` or `s`")),
+    };
+
+    let mut next_workflows = Vec::new();
+    let mut workflow_map = HashMap::new();
+
+    for l in workflows.lines() {
+        let (name, body) = parser.split_once(l, "{")?;
+        let body = parser.strip(body, "", "}")?;
+
+        let (rules, otherwise) = match body.rsplit_once(',') {
+            Some((rules, otherwise)) => (rules.split(',').collect(), otherwise),
+            None => (Vec::new(), body),
+        };
+
+        let rules = rules
+            .into_iter()
+            .map(|r| {
+                let (condition, next) = parser.split_once(r, ":")?;
+                let operator_index = condition
+                    .find(['<', '>'])
+                    .ok_or_else(|| parser.error(condition, "`<` or `>`"))?;
+                let (left, right) = condition.split_at(operator_index);
+
+                next_workflows.push(next);
+
+                Ok(Rule {
+                    category: category(left)?,
+                    equation: if right.starts_with('<') { Ordering::Less } else { Ordering::Greater },
+                    right_value: parser.number::<usize>(&right[1..])?,
+                    next_workflow: String::from(next),
+                })
+            })
+            .collect::<Result<Vec<Rule>, ParseError>>()?;
+
+        next_workflows.push(otherwise);
+        workflow_map.insert(String::from(name), (rules, String::from(otherwise)));
+    }
+
+    if !workflow_map.contains_key("in") {
+        return Err(parser.error(workflows, "a workflow named `in`"));
+    }
+
+    if let Some(unknown) = next_workflows.into_iter().find(|&n| n != "A" && n != "R" && !workflow_map.contains_key(n)) {
+        return Err(parser.error(unknown, "`A`, `R` or a defined workflow"));
+    }
+
+    let parts = parts
+        .lines()
+        .map(|l| {
+            let ratings = parser.strip(l, "{", "}")?;
+            let mut categories = [0; 4];
+
+            for rating in ratings.split(',') {
+                let (left, right) = parser.split_once(rating, "=")?;
+                categories[category(left)?] = parser.number::<usize>(right)?;
+            }
+
+            Ok(categories)
+        })
+        .collect::<Result<Vec<Categories>, ParseError>>()?;
+
+    Ok((
+        Workflows { workflow_map },
+        parts,
+    ))
+}
+
+fn puzzle_1((workflows, categories): &(Workflows, Vec<Categories>)) -> usize {
+    categories
+        .iter()
+        .filter(|&c| workflows.part_is_accepted(c))
+        .map(|c| c.iter().sum::<usize>())
+        .sum()
+}
+
+fn puzzle_2((workflows, _): &(Workflows, Vec<Categories>)) -> usize {
+    workflows.count_accepted_combinations("in", &Cuboid::new([Interval::inclusive(1, 4000); 4]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle_1_example() {
+        let input = parse(include_str!("../../data/day19/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), 19114);
+    }
+
+    #[test]
+    fn test_puzzle_1() {
+        let input = parse(include_str!("../../data/day19/input.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), 330820);
+    }
+
+    #[test]
+    fn test_puzzle_2_example() {
+        let input = parse(include_str!("../../data/day19/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), 167409079868000);
+    }
+
+    #[test]
+    fn test_puzzle_2() {
+        let input = parse(include_str!("../../data/day19/input.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), 123972546935551);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse("in{y>10:A,R}\n\n{x=1,m=2,a=3,s=4}").err().unwrap();
+        assert_eq!((error.line, error.column, error.found.as_str()), (1, 4, "y"));
+
+        let error = parse("in{x>10:qs,R}\n\n{x=1,m=2,a=3,s=4}").err().unwrap();
+        assert_eq!((error.line, error.column, error.found.as_str()), (1, 9, "qs"));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,153 @@
+use std::cmp::max;
+use std::collections::HashMap;
+use crate::parse::{ParseError, Parser};
+use crate::solution::{Solution, SolveError};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Game>;
+    type Output = i32;
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(raw_input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<i32, SolveError> {
+        Ok(puzzle_1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Option<i32>, SolveError> {
+        Ok(Some(puzzle_2(input)))
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+type Game = Vec<Vec<(Color, i32)>>;
+
+fn parse(raw_input: &str) -> Result<Vec<Game>, ParseError>
+{
+    let parser = Parser::new(raw_input);
+
+    parser
+        .lines()
+        .map(|game| {
+            let (_, draws) = parser.split_once(game, ": ")?;
+
+            draws
+                .split("; ")
+                .map(|subset| {
+                    subset
+                        .split(", ")
+                        .map(|color_count| {
+                            let (count, color) = parser.split_once(color_count, " ")?;
+
+                            let color = match color {
+                                "red" => Color::Red,
+                                "green" => Color::Green,
+                                "blue" => Color::Blue,
+                                _ => return Err(parser.error(color, "`red`, `green` or `blue`")),
+                            };
+
+                            Ok((color, parser.number(count)?))
+                        })
+                        .collect()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn puzzle_1(data: &[Game]) -> i32 {
+    data
+        .iter()
+        .enumerate()
+        .filter(|&(_, game)| {
+            game
+                .iter()
+                .all(|draws| {
+                    draws
+                        .iter()
+                        .all(|&(color, count)| {
+                            count <= match color {
+                                Color::Red => 12,
+                                Color::Green => 13,
+                                Color::Blue => 14,
+                            }
+                        })
+                })
+        })
+        .map(|(game_id, _)| (game_id + 1) as i32)
+        .sum()
+}
+
+fn puzzle_2(data: &[Game]) -> i32 {
+    data
+        .iter()
+        .map(|subsets| {
+            let mut color_counts = HashMap::from([(Color::Red, 0), (Color::Blue, 0), (Color::Green, 0)]);
+
+            subsets
+                .iter()
+                .for_each(|subset| {
+                    subset
+                        .iter()
+                        .for_each(| &(color, count) | {
+                            color_counts.insert(color, max(color_counts[&color], count));
+                        })
+                });
+
+            color_counts.values().product::<i32>()
+        })
+        .sum()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle_1_example() {
+        let input = parse(include_str!("../../data/day2/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), 8);
+    }
+
+    #[test]
+    fn test_puzzle_1() {
+        let input = parse(include_str!("../../data/day2/input.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), 1734);
+    }
+
+    #[test]
+    fn test_puzzle_2_example() {
+        let input = parse(include_str!("../../data/day2/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), 2286);
+    }
+
+    #[test]
+    fn test_puzzle_2() {
+        let input = parse(include_str!("../../data/day2/input.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), 70387);
+    }
+
+    #[test]
+    fn test_parse_unknown_color() {
+        let error = parse("Game 1: 3 blue, 4 red\nGame 2: 1 purple").err().unwrap();
+
+        assert_eq!((error.line, error.column), (2, 11));
+        assert_eq!(error.found, "purple");
+    }
+}
\ No newline at end of file
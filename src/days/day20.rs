@@ -0,0 +1,266 @@
+use std::collections::{HashMap, VecDeque};
+use log::{debug, log_enabled, trace, Level};
+use crate::graph::Graph;
+use crate::number_theory::lcm_all;
+use crate::parse::{ParseError, Parser};
+use crate::solution::{Solution, SolveError};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input<'a> = HashMap<&'a str, (ModuleType, Vec<&'a str>)>;
+    type Output = i128;
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(raw_input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<i128, SolveError> {
+        Ok(i128::from(puzzle_1(input)))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Option<i128>, SolveError> {
+        puzzle_2(input).map(Some)
+    }
+}
+
+pub enum ModuleType { Broadcast, FlipFlop, Conjunction }
+
+fn parse(raw_input: &str) -> Result<HashMap<&str, (ModuleType, Vec<&str>)>, ParseError> {
+    let parser = Parser::new(raw_input);
+
+    let map = parser
+        .lines()
+        .map(|l| {
+            let (component_name, output_list) = parser.split_once(l, " -> ")?;
+            let outputs: Vec<&str> = output_list.split(", ").collect();
+
+            match component_name.split_at_checked(1) {
+                Some(("%", name)) => Ok((name, (ModuleType::FlipFlop, outputs))),
+                Some(("&", name)) => Ok((name, (ModuleType::Conjunction, outputs))),
+                _ if component_name == "broadcaster" => Ok((component_name, (ModuleType::Broadcast, outputs))),
+                _ => Err(parser.error(component_name, "`%`, `&` or `broadcaster`")),
+            }
+        })
+        .collect::<Result<HashMap<&str, (ModuleType, Vec<&str>)>, ParseError>>()?;
+
+    // The button sends its pulse to the broadcaster
+    if !map.contains_key("broadcaster") {
+        return Err(parser.error(parser.end(), "a `broadcaster` module"));
+    }
+
+    Ok(map)
+}
+
+/// The modules by node id of the module graph, pulses follow its edges. Outputs that are no module, like `rx`, have
+/// none.
+struct Configuration<'a> {
+    graph: Graph<'a>,
+    modules: Vec<Option<Box<dyn Module>>>,
+    button: usize,
+    broadcaster: usize,
+}
+
+impl<'a> Configuration<'a> {
+    fn from_map(map: &HashMap<&'a str, (ModuleType, Vec<&'a str>)>) -> Self {
+        let mut graph = module_graph(map);
+        let (button, broadcaster) = graph.add_edge("button", "broadcaster");
+
+        let modules = (0..graph.node_count())
+            .map(|node| {
+                let module: Box<dyn Module> = match map.get(graph.name(node)) {
+                    None if node == button => Box::new(Button),
+                    None => return None,
+                    Some((ModuleType::Broadcast, _)) => Box::new(Broadcaster),
+                    Some((ModuleType::FlipFlop, _)) => Box::new(FlipFlop { state: false }),
+                    Some((ModuleType::Conjunction, _)) => {
+                        let state = HashMap::from_iter(
+                            (0..graph.node_count())
+                                .filter(|&input| graph.neighbors(input).contains(&node))
+                                .map(|input| (input, Pulse::Low))
+                        );
+
+                        Box::new(Conjunction { state })
+                    },
+                };
+
+                Some(module)
+            })
+            .collect();
+
+        Self { graph, modules, button, broadcaster }
+    }
+
+    // Pushes the button once and passes every pulse on until none are left, `on_pulse` sees each pulse as
+    // `(from, to, pulse)`
+    fn push_button(&mut self, mut on_pulse: impl FnMut(usize, usize, Pulse)) {
+        let mut queue = VecDeque::from([(self.broadcaster, Pulse::Low, self.button)]);
+
+        while let Some((node, pulse, from)) = queue.pop_front() {
+            on_pulse(from, node, pulse);
+
+            if let Some(module) = &mut self.modules[node] {
+                if let Some(new_pulse) = module.handle_pulse(&pulse, from) {
+                    for &output in self.graph.neighbors(node) {
+                        queue.push_back((output, new_pulse, node));
+                    }
+                }
+            }
+        }
+    }
+
+    fn send_pulse(&mut self) -> (i32, i32) {
+        let mut low_pulse_count = 0;
+        let mut high_pulse_count = 0;
+
+        self.push_button(|_, _, pulse| match pulse {
+            Pulse::Low => low_pulse_count += 1,
+            Pulse::High => high_pulse_count += 1,
+        });
+
+        (low_pulse_count, high_pulse_count)
+    }
+
+    fn send_pulse_until(&mut self, until_node: usize, until_pulse: Pulse) -> i64 {
+        let mut count = 0;
+        let mut found = false;
+
+        while !found {
+            self.push_button(|from, _, pulse| found |= from == until_node && pulse == until_pulse);
+            count += 1;
+        }
+
+        count
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Pulse { Low, High }
+
+trait Module {
+    fn handle_pulse(&mut self, pulse: &Pulse, from: usize) -> Option<Pulse>;
+}
+
+struct Button;
+impl Module for Button {
+    fn handle_pulse(&mut self, pulse: &Pulse, _: usize) -> Option<Pulse> {
+        Some(*pulse)
+    }
+}
+
+struct Broadcaster;
+impl Module for Broadcaster {
+    fn handle_pulse(&mut self, pulse: &Pulse, _: usize) -> Option<Pulse> {
+        Some(*pulse)
+    }
+}
+
+struct FlipFlop { state: bool }
+impl Module for FlipFlop {
+    fn handle_pulse(&mut self, pulse: &Pulse, _: usize) -> Option<Pulse> {
+        if pulse == &Pulse::High {
+            return None;
+        }
+
+        // Flip state
+        self.state = !self.state;
+
+        Some(if self.state { Pulse::High } else { Pulse::Low })
+    }
+}
+
+struct Conjunction { state: HashMap<usize, Pulse> }
+impl Module for Conjunction {
+    fn handle_pulse(&mut self, pulse: &Pulse, from: usize) -> Option<Pulse> {
+        self.state.insert(from, *pulse);
+
+        Some(if self.state.values().all(|&p| p == Pulse::High) { Pulse::Low } else { Pulse::High })
+    }
+}
+
+fn puzzle_1(data: &HashMap<&str, (ModuleType, Vec<&str>)>) -> i32 {
+    let mut configuration = Configuration::from_map(data);
+
+    let mut total_low_pulse_count = 0;
+    let mut total_high_pulse_count = 0;
+    for _ in 0..1000 {
+        let (low_pulse_count, high_pulse_count) = configuration.send_pulse();
+        total_low_pulse_count += low_pulse_count;
+        total_high_pulse_count += high_pulse_count;
+    }
+
+    total_low_pulse_count * total_high_pulse_count
+}
+
+fn puzzle_2(data: &HashMap<&str, (ModuleType, Vec<&str>)>) -> Result<i128, SolveError> {
+    let mut configuration = Configuration::from_map(data);
+    let graph = configuration.graph.clone();
+
+    let before_rx = graph
+        .id("rx")
+        .and_then(|rx| (0..graph.node_count()).find(|&node| graph.neighbors(node).contains(&rx)))
+        .ok_or_else(|| SolveError::new("no module sends pulses to rx"))?;
+
+    debug!("{} sends pulses to rx", graph.name(before_rx));
+
+    if log_enabled!(Level::Trace) {
+        trace!("Module graph:\n{}", graph.to_dot());
+    }
+
+    // Find the iteration where all components that output to the conjunction before rx send a high pulse
+    let cycle_lengths = (0..graph.node_count())
+        .filter(|&node| graph.neighbors(node).contains(&before_rx))
+        .map(|node| {
+            configuration.send_pulse_until(node, Pulse::High); // Skip first time it find it to exclude the offset
+            let cycle_length = configuration.send_pulse_until(node, Pulse::High);
+
+            debug!("{} sends a high pulse to {} every {cycle_length} button presses", graph.name(node), graph.name(before_rx));
+            cycle_length as i128
+        });
+
+    lcm_all(cycle_lengths).ok_or_else(|| SolveError::new("the number of button presses does not fit in an i128"))
+}
+
+fn module_graph<'a>(data: &HashMap<&'a str, (ModuleType, Vec<&'a str>)>) -> Graph<'a> {
+    let mut graph = Graph::directed();
+    let mut names: Vec<&str> = data.keys().copied().collect();
+    names.sort_unstable();
+
+    for name in names {
+        for &output in &data[name].1 {
+            graph.add_edge(name, output);
+        }
+    }
+
+    graph
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle_1_example() {
+        let input = parse(include_str!("../../data/day20/input_example.txt")).unwrap();
+        let input2 = parse(include_str!("../../data/day20/input_example2.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), 32000000);
+        assert_eq!(puzzle_1(&input2), 11687500);
+    }
+
+    #[test]
+    fn test_puzzle_1() {
+        let input = parse(include_str!("../../data/day20/input.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), 712543680);
+    }
+
+    #[test]
+    fn test_puzzle_2() {
+        let input = parse(include_str!("../../data/day20/input.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), Ok(238920142622879));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,127 @@
+use crate::animation::Recorder;
+use crate::bit_grid::BitGrid;
+use crate::grid::Grid;
+use crate::image::Rgb;
+use crate::parse::{ParseError, Parser};
+use crate::polynomial::DifferenceTable;
+use crate::render::{Canvas, Cell};
+use crate::solution::{Part, Solution, SolveError};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input<'a> = Grid<char>;
+    type Output = usize;
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(raw_input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<usize, SolveError> {
+        Ok(puzzle_1(input, 64))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Option<usize>, SolveError> {
+        Ok(Some(puzzle_2(input, 26501365)))
+    }
+
+    // The second part walks too far to keep a frame of every step
+    fn animate(input: &Self::Input<'_>, part: Part) -> Option<Recorder> {
+        (part == 1).then(|| animate(input, 64))
+    }
+}
+
+fn parse(raw_input: &str) -> Result<Grid<char>, ParseError>
+{
+    let parser = Parser::new(raw_input);
+    let data = parser.char_grid(raw_input, ".#S")?;
+
+    if data.position(|&c| c == 'S').is_none() {
+        return Err(parser.error(parser.end(), "a starting position `S`"));
+    }
+
+    Ok(data)
+}
+
+fn puzzle_1(data: &Grid<char>, steps: usize) -> usize {
+    walk(data, steps, |_, _| {}).count_ones()
+}
+
+fn puzzle_2(data: &Grid<char>, total_steps: usize) -> usize {
+    let width = data.width();
+    let offset = total_steps % width;
+
+    // The start row and column are free of rocks, so the reachable area grows by a whole map in every direction each
+    // `width` steps and the plot count is quadratic in the number of maps crossed
+    let samples = (0..3)
+        .map(|maps| puzzle_1(data, offset + maps * width) as i128)
+        .collect::<Vec<i128>>();
+
+    DifferenceTable::new(0, &samples).evaluate((total_steps / width) as i128) as usize
+}
+
+// The garden plots that can be reached after every step
+fn animate(data: &Grid<char>, steps: usize) -> Recorder {
+    let mut recorder = Recorder::new();
+
+    walk(data, steps, |garden, reachable| {
+        let canvas = Canvas::new(garden.height(), garden.width(), Cell::new('.', Rgb::DARK_GRAY))
+            .with_cells((!garden).ones(), Cell::new('#', Rgb::DARK_GREEN))
+            .with_cells(reachable.ones(), Cell::new('O', Rgb::YELLOW));
+
+        recorder.record(&canvas);
+    });
+
+    recorder
+}
+
+// The plots reachable in exactly the number of steps, `on_step` sees the garden and the plots at the start and after
+// every step
+fn walk(data: &Grid<char>, steps: usize, mut on_step: impl FnMut(&BitGrid, &BitGrid)) -> BitGrid {
+    let (height, width) = (data.height(), data.width());
+
+    // Repeat the map far enough in every direction that no step leaves it, with the start in the middle copy
+    let (vertical_copies, horizontal_copies) = (2 * (steps / height + 1) + 1, 2 * (steps / width + 1) + 1);
+    let garden = BitGrid::from_fn(height * vertical_copies, width * horizontal_copies, |(y, x)| data[(y % height, x % width)] != '#');
+
+    let (start_y, start_x) = data.position(|&c| c == 'S').unwrap();
+    let mut reachable = BitGrid::new(garden.height(), garden.width());
+    reachable.insert((start_y + height * (vertical_copies / 2), start_x + width * (horizontal_copies / 2)));
+
+    on_step(&garden, &reachable);
+
+    for _ in 0..steps {
+        reachable = &reachable.neighbors() & &garden;
+        on_step(&garden, &reachable);
+    }
+
+    reachable
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle_1_example() {
+        let input = parse(include_str!("../../data/day21/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input, 6), 16);
+    }
+
+    #[test]
+    fn test_puzzle_1() {
+        let input = parse(include_str!("../../data/day21/input.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input, 64), 3689);
+    }
+
+    #[test]
+    fn test_puzzle_2() {
+        let input = parse(include_str!("../../data/day21/input.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input, 26501365), 610158187362102);
+    }
+}
\ No newline at end of file
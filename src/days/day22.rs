@@ -0,0 +1,284 @@
+use std::cmp::{max, min, Ordering};
+use itertools::Itertools;
+use crate::animation::Recorder;
+use crate::image::Rgb;
+use crate::parse::{ParseError, Parser};
+use crate::render::{Canvas, Cell};
+use crate::solution::{Part, Solution, SolveError};
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    type Input<'a> = Vec<Block>;
+    type Output = usize;
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(raw_input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<usize, SolveError> {
+        Ok(puzzle_1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Option<usize>, SolveError> {
+        Ok(Some(puzzle_2(input)))
+    }
+
+    fn animate(input: &Self::Input<'_>, part: Part) -> Option<Recorder> {
+        Some(animate(input, part))
+    }
+}
+
+fn parse(raw_input: &str) -> Result<Vec<Block>, ParseError>
+{
+    let parser = Parser::new(raw_input);
+
+    let coordinates = |fragment: &str| {
+        fragment
+            .split(',')
+            .map(|c| parser.number::<usize>(c))
+            .collect::<Result<Vec<usize>, ParseError>>()?
+            .into_iter()
+            .collect_tuple()
+            .ok_or_else(|| parser.error(fragment, "three coordinates"))
+    };
+
+    let blocks = parser
+        .lines()
+        .map(|l| {
+            let (a, b) = parser.split_once(l, "~")?;
+            let (ax, ay, az) = coordinates(a)?;
+            let (bx, by, bz) = coordinates(b)?;
+
+            Ok(Block {
+                x0: min(ax, bx),
+                x1: max(ax, bx),
+                y0: min(ay, by),
+                y1: max(ay, by),
+                z0: min(az, bz),
+                z1: max(az, bz),
+            })
+        })
+        .collect::<Result<Vec<Block>, ParseError>>()?;
+
+    Ok(blocks.into_iter().sorted().collect())
+}
+
+fn puzzle_1(data: &[Block]) -> usize {
+    let mut tree: BlockTree = Default::default();
+
+    data.iter().for_each(|b| tree.add_block(b));
+
+    tree.nodes
+        .iter()
+        .filter(|&n| n.children.iter().all(|&c| tree.nodes[c].parents.len() > 1))
+        .count()
+}
+
+fn puzzle_2(data: &[Block]) -> usize {
+    let mut tree: BlockTree = Default::default();
+
+    data.iter().for_each(|b| tree.add_block(b));
+
+    let skips: Vec<usize> = tree.nodes
+        .iter()
+        .filter(|&n| n.children.iter().all(|&c| tree.nodes[c].parents.len() > 1))
+        .map(|n| n.block_index)
+        .collect();
+
+    tree
+        .nodes
+        .iter()
+        .filter(|&n| !skips.contains(&n.block_index))
+        .map(|start_node| chain_reaction(&tree, start_node.block_index, |_| {}).len() - 1)
+        .sum()
+}
+
+// The bricks settling one by one from the lowest up in the first part, the disintegration that makes the most bricks
+// fall in the second
+fn animate(data: &[Block], part: Part) -> Recorder {
+    let size = (
+        data.iter().map(|b| b.x1).max().unwrap_or(0),
+        data.iter().map(|b| b.y1).max().unwrap_or(0),
+        data.iter().map(|b| b.z1).max().unwrap_or(0),
+    );
+    let mut tree: BlockTree = Default::default();
+    let mut recorder = Recorder::new();
+
+    if part == 1 {
+        recorder.record(&draw(data.iter().map(|b| (b, Rgb::GRAY)), size));
+
+        for (i, block) in data.iter().enumerate() {
+            tree.add_block(block);
+
+            let settled = tree.blocks.iter().enumerate().map(|(j, b)| (b, brick_color(j)));
+            recorder.record(&draw(settled.chain(data[i + 1..].iter().map(|b| (b, Rgb::GRAY))), size));
+        }
+    } else {
+        data.iter().for_each(|b| tree.add_block(b));
+
+        let start = (0..tree.blocks.len())
+            .max_by_key(|&i| chain_reaction(&tree, i, |_| {}).len())
+            .unwrap_or(0);
+
+        recorder.record(&draw(tree.blocks.iter().enumerate().map(|(j, b)| (b, brick_color(j))), size));
+        chain_reaction(&tree, start, |removed_blocks| {
+            let colored = tree.blocks
+                .iter()
+                .enumerate()
+                .map(|(j, b)| (b, if removed_blocks.contains(&j) { Rgb::RED } else { brick_color(j) }));
+
+            recorder.record(&draw(colored, size));
+        });
+    }
+
+    recorder
+}
+
+fn brick_color(index: usize) -> Rgb {
+    Rgb::BLUE.mix(Rgb::GREEN, (index % 5) as f64 / 4.0)
+}
+
+// The bricks seen from the front along the y axis and from the side along the x axis, next to each other
+fn draw<'a>(blocks: impl IntoIterator<Item = (&'a Block, Rgb)>, (max_x, max_y, max_z): (usize, usize, usize)) -> Canvas {
+    let ground = (0..=max_x).chain(max_x + 2..=max_x + 2 + max_y).map(|x| (max_z, x));
+    let mut canvas = Canvas::new(max_z + 1, max_x + max_y + 3, Cell::new('.', Rgb::BLACK))
+        .with_cells(ground, Cell::new('-', Rgb::GRAY));
+
+    for (block, color) in blocks {
+        let rows = (block.z0..=block.z1).map(|z| max_z - z);
+
+        canvas = canvas
+            .with_cells(rows.clone().cartesian_product(block.x0..=block.x1), Cell::new('#', color))
+            .with_cells(rows.cartesian_product(max_x + 2 + block.y0..=max_x + 2 + block.y1), Cell::new('#', color));
+    }
+
+    canvas
+}
+
+// The disintegrated block and every block that falls because of it, `on_step` sees them at the start and after every
+// wave of falling blocks
+fn chain_reaction(tree: &BlockTree, start: usize, mut on_step: impl FnMut(&[usize])) -> Vec<usize> {
+    let mut removed_blocks = Vec::from([start]);
+    on_step(&removed_blocks);
+
+    loop {
+        let falling_blocks: Vec<usize> = tree.nodes
+            .iter()
+            .filter(|&n| !n.parents.is_empty())
+            .filter(|&n| !removed_blocks.contains(&n.block_index))
+            .filter(|&n| n.parents.iter().all(|p| removed_blocks.contains(p)))
+            .map(|n| n.block_index)
+            .collect();
+
+        if falling_blocks.is_empty() {
+            return removed_blocks;
+        }
+
+        removed_blocks.extend(falling_blocks);
+        on_step(&removed_blocks);
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Block { x0: usize, x1: usize, y0: usize, y1: usize, z0: usize, z1: usize }
+impl Block {
+    fn has_intersection(&self, other: &Self) -> bool {
+        max(self.x0, other.x0) <= min(self.x1, other.x1) && max(self.y0, other.y0) <= min(self.y1, other.y1)
+    }
+
+    fn fall_to(&self, z: usize) -> Self {
+        Self {
+            x0: self.x0,
+            x1: self.x1,
+            y0: self.y0,
+            y1: self.y1,
+            z0: z + 1,
+            z1: z + 1 + (self.z1 - self.z0),
+        }
+    }
+}
+
+impl Ord for Block {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.z0.cmp(&other.z0)
+    }
+}
+
+impl PartialOrd for Block {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+struct Node { block_index: usize, parents: Vec<usize>, children: Vec<usize> }
+#[derive(Default)]
+struct BlockTree { blocks: Vec<Block>, nodes: Vec<Node>, ground: Vec<usize> }
+
+impl BlockTree {
+    fn add_block(&mut self, block: &Block) {
+        let own_index = self.blocks.len();
+        let sorted_blocks: Vec<&Block> = self.blocks.iter().sorted_by(|&a, &b| b.z1.cmp(&a.z1)).collect();
+        if let Some(b) = sorted_blocks.into_iter().find(|&b| b.has_intersection(block)) {
+            let mut node = Node {
+                block_index: own_index,
+                parents: vec![],
+                children: vec![],
+            };
+
+            self.blocks
+                .iter()
+                .enumerate()
+                .filter(|&(_, ob)| ob.z1 == b.z1 && ob.has_intersection(block))
+                .for_each(|(j, _)| {
+                    node.parents.push(j);
+                    self.nodes[j].children.push(own_index);
+                });
+            self.blocks.push(block.fall_to(b.z1));
+            self.nodes.push(node);
+        } else {
+            self.nodes.push(Node {
+                block_index: own_index,
+                parents: vec![],
+                children: vec![],
+            });
+            self.ground.push(own_index);
+            self.blocks.push(block.fall_to(0));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle_1_example() {
+        let input = parse(include_str!("../../data/day22/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), 5);
+    }
+
+    #[test]
+    fn test_puzzle_1() {
+        let input = parse(include_str!("../../data/day22/input.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), 519);
+    }
+
+    #[test]
+    fn test_puzzle_2_example() {
+        let input = parse(include_str!("../../data/day22/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), 7);
+    }
+
+    #[test]
+    fn test_puzzle_2() {
+        let input = parse(include_str!("../../data/day22/input.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), 109531);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,310 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use log::debug;
+use crate::Direction;
+use crate::flood_fill::flood_fill;
+use crate::grid::Grid;
+use crate::image::Rgb;
+use crate::parse::{ParseError, Parser};
+use crate::render::{Canvas, Cell};
+use crate::solution::{Part, Solution, SolveError};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input<'a> = Grid<char>;
+    type Output = usize;
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(raw_input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<usize, SolveError> {
+        puzzle_1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Option<usize>, SolveError> {
+        puzzle_2(input).map(Some)
+    }
+
+    fn render(input: &Self::Input<'_>, part: Part) -> Option<Canvas> {
+        render(input, part)
+    }
+}
+
+fn parse(raw_input: &str) -> Result<Grid<char>, ParseError> {
+    let parser = Parser::new(raw_input);
+    let data = parser.char_grid(raw_input, "#.^>v<")?;
+
+    if data.height() < 2 || data.width() < 2 {
+        return Err(parser.error(raw_input, "a map of at least 2 rows and 2 columns"));
+    }
+
+    Ok(data)
+}
+
+fn puzzle_1(data: &Grid<char>) -> Result<usize, SolveError> {
+    check_trail(data, false)?;
+    let graph = build_graph(data, false);
+
+    Ok(find_longest_path(&graph, 1, graph.len() - 2).cost)
+}
+
+fn puzzle_2(data: &Grid<char>) -> Result<usize, SolveError> {
+    check_trail(data, true)?;
+    let graph = build_graph(data, true);
+
+    Ok(find_longest_path(&graph, 1, graph.len() - 2).cost)
+}
+
+// The hike starts on the second tile of the top row and ends on the second to last tile of the bottom row
+fn check_trail(data: &Grid<char>, ignore_slopes: bool) -> Result<(), SolveError> {
+    let (start, end) = ((0, 1), (data.height() - 1, data.width() - 2));
+    let trails = flood_fill(data, [start], |from, to| {
+        data[to] != '#'
+            && (ignore_slopes || Direction::try_from(data[from]).ok().is_none_or(|slope| data.coordinate_in_direction(from, slope) == Some(to)))
+    });
+
+    if data[start] == '#' || !trails.get(end) {
+        return Err(SolveError::new("no trail leads from the start to the end"));
+    }
+
+    Ok(())
+}
+
+// The forest with the longest hike, following the trails between the intersections it passes
+fn render(data: &Grid<char>, part: Part) -> Option<Canvas> {
+    check_trail(data, part == 2).ok()?;
+
+    let width = data.width();
+    let graph = build_graph(data, part == 2);
+    let start = 1;
+    let hike = find_longest_path(&graph, start, graph.len() - 2);
+
+    let mut cells = vec![(0, start)];
+    let mut position = start;
+    for &next in &hike.visited {
+        let edge = graph[position]
+            .iter()
+            .filter(|edge| edge.position == next)
+            .max_by_key(|edge| edge.cost)
+            .unwrap();
+
+        cells.extend(edge.trail.iter().map(|&p| (p / width, p % width)));
+        position = next;
+    }
+
+    let canvas = Canvas::from_grid(data, |&c| match c {
+        '#' => Cell::new(c, Rgb::DARK_GREEN),
+        '.' => Cell::new(c, Rgb::GRAY),
+        _ => Cell::new(c, Rgb::ORANGE),
+    })
+        .with_path(&cells, Rgb::RED)
+        .with_legend(Rgb::ORANGE, "slope")
+        .with_legend(Rgb::RED, &format!("longest hike, {} steps", hike.cost));
+
+    Some(canvas)
+}
+
+fn build_graph(data: &Grid<char>, ignore_slopes: bool) -> Vec<Vec<Edge>> {
+    let height = data.height();
+    let width = data.width();
+    let mut graph: Vec<Vec<Edge>> = Vec::with_capacity(width * height);
+
+    for i in 0..height {
+        for j in 0..width {
+            let mut edges = Vec::new();
+
+            let slope = Direction::try_from(data[(i, j)]).ok().filter(|_| !ignore_slopes);
+
+            match (data[(i, j)], slope) {
+                ('#', _) => {},
+                (_, Some(direction)) => edges.extend(
+                    data
+                        .coordinate_in_direction((i, j), direction)
+                        .map(|(ny, nx)| Edge {position: ny * width + nx, cost: 1, trail: Vec::new()})
+                ),
+                _ => edges.extend(Vec::from_iter(
+                    data
+                        .von_neumann_compass((i, j))
+                        .into_iter()
+                        .filter_map(|(_, (ny, nx))| {
+                            if data[(ny, nx)] == '#' {
+                                None
+                            } else {
+                                Some(Edge {position: ny * width + nx, cost: 1, trail: Vec::new()})
+                            }
+                        })
+                )),
+            }
+
+            graph.push(edges);
+        }
+    }
+
+    for i in 0..graph.len() {
+        if graph[i].is_empty() || graph[i].len() == 2 {
+            continue;
+        }
+
+        for j in 0..graph[i].len() {
+            let mut last_pos = i;
+            let mut pos = graph[i][j].position;
+            let mut trail = vec![pos];
+
+            // Search for next intersection
+            while graph[pos].len() == 2 {
+                let new_pos = if graph[pos][0].position != last_pos { graph[pos][0].position } else { graph[pos][1].position };
+                last_pos = pos;
+                pos = new_pos;
+                trail.push(pos);
+            }
+
+            graph[i][j].position = pos;
+            graph[i][j].cost = trail.len();
+            graph[i][j].trail = trail;
+        }
+    }
+
+    debug!(
+        "Trail map has {} intersections connected by {} trails",
+        graph.iter().filter(|edges| !edges.is_empty() && edges.len() != 2).count(),
+        graph.iter().filter(|edges| edges.len() != 2).map(|edges| edges.len()).sum::<usize>(),
+    );
+
+    graph
+}
+
+fn find_longest_path(graph: &[Vec<Edge>], start: usize, end: usize) -> Path {
+    let mut longest: Option<Path> = None;
+    let mut heap = BinaryHeap::new();
+
+    graph[start]
+        .iter()
+        .for_each(|edge| heap.push(Path::from_edge(edge)));
+
+    while let Some(path) = heap.pop() {
+        if path.position == end {
+            if longest.as_ref().is_none_or(|longest| path.cost > longest.cost) {
+                longest = Some(path);
+            }
+
+            continue;
+        }
+
+        graph[path.position]
+            .iter()
+            .for_each(|edge| {
+                if path.visited.contains(&edge.position) {
+                    return;
+                }
+
+                heap.push(path.add_edge(edge));
+            })
+    }
+
+    longest.expect("the end can be reached from the start")
+}
+
+struct Edge {
+    position: usize,
+    cost: usize,
+    // The cells walked to reach the position, for rendering
+    trail: Vec<usize>,
+}
+
+#[derive(Clone, Eq, PartialEq)]
+struct Path {
+    cost: usize,
+    position: usize,
+    visited: Vec<usize>,
+}
+
+impl Path {
+    fn from_edge(edge: &Edge) -> Self {
+        Self {
+            cost: edge.cost,
+            position: edge.position,
+            visited: Vec::from([edge.position]),
+        }
+    }
+
+    fn add_edge(&self, edge: &Edge) -> Self {
+        let mut visited= Vec::from_iter(self.visited.iter().copied());
+        visited.push(edge.position);
+
+        Self {
+            cost: self.cost + edge.cost,
+            position: edge.position,
+            visited,
+        }
+    }
+}
+
+impl Ord for Path {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.cmp(&self.cost)
+    }
+}
+
+impl PartialOrd for Path {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle_1_example() {
+        let input = parse(include_str!("../../data/day23/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), Ok(94));
+    }
+
+    #[test]
+    fn test_puzzle_1() {
+        let input = parse(include_str!("../../data/day23/input.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), Ok(2318));
+    }
+
+    #[test]
+    fn test_puzzle_2_example() {
+        let input = parse(include_str!("../../data/day23/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), Ok(154));
+    }
+
+    #[test]
+    fn test_puzzle_2() {
+        let input = parse(include_str!("../../data/day23/input.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), Ok(6426));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("").is_err());
+        assert!(parse("#").is_err());
+        assert!(parse("#x#\n#.#").is_err());
+    }
+
+    #[test]
+    fn test_no_trail() {
+        let walled_off = parse("#.#\n###\n#.#").unwrap();
+
+        assert!(puzzle_1(&walled_off).is_err());
+        assert!(puzzle_2(&walled_off).is_err());
+
+        // The slope only blocks the way while slopes are followed
+        let uphill = parse("#.##\n#<.#\n##.#").unwrap();
+
+        assert!(puzzle_1(&uphill).is_err());
+        assert_eq!(puzzle_2(&uphill), Ok(3));
+    }
+}
\ No newline at end of file
@@ -2,32 +2,60 @@ use std::ops::{Add, Mul};
 use itertools::Itertools;
 use z3::{Config, Context, Solver};
 use z3::ast::{Ast, Real, Int};
+use crate::parse::{ParseError, Parser};
+use crate::solution::{Solution, SolveError};
 
-fn main() {
-    let input = parse(include_str!("../../data/day24/input.txt"));
+pub struct Day24;
 
-    println!("Puzzle 1: {}", puzzle_1(&input, (200000000000000.0, 400000000000000.0)));
-    println!("Puzzle 2: {}", puzzle_2(&input));
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input<'a> = Vec<Trajectory>;
+    type Output = usize;
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(raw_input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<usize, SolveError> {
+        Ok(puzzle_1(input, (200000000000000.0, 400000000000000.0)))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Option<usize>, SolveError> {
+        Ok(Some(puzzle_2(input)))
+    }
 }
 
-struct Trajectory {
+pub struct Trajectory {
     x0: i64, y0: i64, z0: i64,
     dx: i64, dy: i64, dz: i64,
 }
 
-fn parse(raw_input: &str) -> Vec<Trajectory>
+fn parse(raw_input: &str) -> Result<Vec<Trajectory>, ParseError>
 {
-    raw_input
+    let parser = Parser::new(raw_input);
+
+    let vector = |fragment: &str| {
+        fragment
+            .split(',')
+            .map(|v| parser.number::<i64>(v.trim()))
+            .collect::<Result<Vec<i64>, ParseError>>()?
+            .into_iter()
+            .collect_tuple()
+            .ok_or_else(|| parser.error(fragment, "three numbers"))
+    };
+
+    parser
         .lines()
         .map(|l| {
-            let (position, velocity) = l.split_once(" @ ").unwrap();
-            let (x0, y0, z0) = position.splitn(3, ", ").map(|v| v.parse::<i64>().unwrap()).collect_tuple().unwrap();
-            let (dx, dy, dz) = velocity.splitn(3, ", ").map(|v| v.parse::<i64>().unwrap()).collect_tuple().unwrap();
+            let (position, velocity) = parser.split_once(l, " @ ")?;
+            let (x0, y0, z0) = vector(position)?;
+            let (dx, dy, dz) = vector(velocity)?;
 
-            Trajectory {
+            Ok(Trajectory {
                 x0, y0, z0,
                 dx, dy, dz,
-            }
+            })
         })
         .collect()
 }
@@ -104,28 +132,28 @@ mod tests {
 
     #[test]
     fn test_puzzle_1_example() {
-        let input = parse(include_str!("../../data/day24/input_example.txt"));
+        let input = parse(include_str!("../../data/day24/input_example.txt")).unwrap();
 
         assert_eq!(puzzle_1(&input, (7.0, 27.0)), 2);
     }
 
     #[test]
     fn test_puzzle_1() {
-        let input = parse(include_str!("../../data/day24/input.txt"));
+        let input = parse(include_str!("../../data/day24/input.txt")).unwrap();
 
         assert_eq!(puzzle_1(&input, (200000000000000.0, 400000000000000.0)), 11246);
     }
 
     #[test]
     fn test_puzzle_2_example() {
-        let input = parse(include_str!("../../data/day24/input_example.txt"));
+        let input = parse(include_str!("../../data/day24/input_example.txt")).unwrap();
 
         assert_eq!(puzzle_2(&input), 47);
     }
 
     #[test]
     fn test_puzzle_2() {
-        let input = parse(include_str!("../../data/day24/input.txt"));
+        let input = parse(include_str!("../../data/day24/input.txt")).unwrap();
 
         assert_eq!(puzzle_2(&input), 716599937560103);
     }
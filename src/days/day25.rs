@@ -0,0 +1,120 @@
+use std::cmp::{max, min};
+use std::collections::HashMap;
+use itertools::Itertools;
+use log::{debug, info};
+use crate::graph::Graph;
+use crate::parse::{ParseError, Parser};
+use crate::shortest_path::dijkstra;
+use crate::solution::{Solution, SolveError};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Input<'a> = WiringDiagram<'a>;
+    type Output = usize;
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(raw_input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<usize, SolveError> {
+        puzzle_1(input)
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> Result<Option<usize>, SolveError> {
+        Ok(None)
+    }
+}
+
+type WiringDiagram<'a> = Graph<'a>;
+
+fn parse(raw_input: &str) -> Result<WiringDiagram<'_>, ParseError>
+{
+    let parser = Parser::new(raw_input);
+    let mut graph = Graph::undirected();
+
+    for l in parser.lines() {
+        let (component, connections) = parser.split_once(l, ": ")?;
+
+        for connection in connections.split(' ') {
+            graph.add_edge(component, connection);
+        }
+    }
+
+    Ok(graph)
+}
+
+fn puzzle_1(data: &WiringDiagram) -> Result<usize, SolveError> {
+    let mut graph = data.clone();
+    let mut edge_frequencies: HashMap<(usize, usize), usize> = HashMap::new();
+
+    debug!("Wiring diagram has {} components and {} wires", graph.node_count(), graph.edge_count());
+
+    // The wires between the two groups are part of the most shortest paths between a sample of components, spread
+    // evenly over all pairs
+    let pair_count = graph.node_count() * graph.node_count().saturating_sub(1) / 2;
+
+    for (from, to) in (0..graph.node_count()).tuple_combinations().step_by((pair_count / 10000).max(1)) {
+        let Some(shortest_path) = dijkstra([from], |&node| graph.neighbors(node).iter().map(|&n| (n, 1)), |&node| node == to) else {
+            continue;
+        };
+
+        for (a, b) in shortest_path.path.into_iter().tuple_windows::<(usize, usize)>() {
+            *edge_frequencies.entry((min(a, b), max(a, b))).or_insert(0) += 1;
+        }
+    }
+
+    let removed_edges: Vec<(usize, usize)> = edge_frequencies
+        .into_iter()
+        .sorted_by(|&(edge_a, count_a), &(edge_b, count_b)| count_b.cmp(&count_a).then(edge_a.cmp(&edge_b)))
+        .take(3)
+        .map(|(edge, _)| edge)
+        .collect();
+
+    for &(a, b) in &removed_edges {
+        debug!("Cutting the wire between {} and {}", graph.name(a), graph.name(b));
+        graph.remove_edge(a, b);
+    }
+
+    let groups = graph.connected_components();
+
+    info!("Groups have {} components", groups.iter().map(|group| group.len().to_string()).collect::<Vec<_>>().join(" and "));
+
+    match groups.as_slice() {
+        [first, second] => Ok(first.len() * second.len()),
+        _ => Err(SolveError::new(format!("cutting the three most used wires leaves {} groups instead of two", groups.len()))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle_1_example() {
+        let input = parse(include_str!("../../data/day25/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), Ok(54));
+    }
+
+    #[test]
+    fn test_puzzle_1() {
+        let input = parse(include_str!("../../data/day25/input.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), Ok(552682));
+    }
+
+    #[test]
+    fn test_puzzle_1_not_two_groups() {
+        assert!(puzzle_1(&parse("").unwrap()).is_err());
+        assert!(puzzle_1(&parse("a: b c").unwrap()).is_err());
+        assert!(puzzle_1(&parse("a: b c d\ne: f").unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("a b").is_err());
+    }
+}
\ No newline at end of file
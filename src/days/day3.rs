@@ -1,25 +1,44 @@
 use std::collections::{HashMap, HashSet};
 use regex::Regex;
+use crate::parse::{ParseError, Parser};
+use crate::solution::{Solution, SolveError};
 
-fn main() {
-    let (grid, grid_width) = parse(include_str!("../../data/day3/input.txt"));
+pub struct Day3;
 
-    puzzle_1(&grid, grid_width);
-    puzzle_2(&grid, grid_width);
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = (String, usize);
+    type Output = i32;
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(raw_input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<i32, SolveError> {
+        Ok(puzzle_1(&input.0, input.1))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Option<i32>, SolveError> {
+        Ok(Some(puzzle_2(&input.0, input.1)))
+    }
 }
 
-fn parse(raw_input: &str) -> (String, usize)
+fn parse(raw_input: &str) -> Result<(String, usize), ParseError>
 {
+    let parser = Parser::new(raw_input);
+
     // Create a border of dots around the grid
-    let lines = raw_input
-        .split('\n')
-        .map(|line| format!(".{line}."))
+    let lines = parser
+        .grid(raw_input, "a digit, `.` or a symbol", |c| c.is_ascii_graphic().then_some(c))?
+        .rows()
+        .map(|row| format!(".{}.", String::from_iter(row)))
         .collect::<Vec<String>>();
 
-    let grid_width = lines.first().unwrap().len();
+    let grid_width = lines.first().map_or(2, |line| line.len());
     let hor_border = str::repeat(".", grid_width);
 
-    (format!("{hor_border}{}{hor_border}", lines.join("")), grid_width)
+    Ok((format!("{hor_border}{}{hor_border}", lines.join("")), grid_width))
 }
 
 fn puzzle_1(grid: &str, grid_width: usize) -> i32 {
@@ -111,28 +130,28 @@ mod tests {
 
     #[test]
     fn test_puzzle_1_example() {
-        let (grid, grid_width) = parse(include_str!("../../data/day3/input_example.txt"));
+        let (grid, grid_width) = parse(include_str!("../../data/day3/input_example.txt")).unwrap();
 
         assert_eq!(puzzle_1(&grid, grid_width), 4361);
     }
 
     #[test]
     fn test_puzzle_1() {
-        let (grid, grid_width) = parse(include_str!("../../data/day3/input.txt"));
+        let (grid, grid_width) = parse(include_str!("../../data/day3/input.txt")).unwrap();
 
         assert_eq!(puzzle_1(&grid, grid_width), 528819);
     }
 
     #[test]
     fn test_puzzle_2_example() {
-        let (grid, grid_width) = parse(include_str!("../../data/day3/input_example.txt"));
+        let (grid, grid_width) = parse(include_str!("../../data/day3/input_example.txt")).unwrap();
 
         assert_eq!(puzzle_2(&grid, grid_width), 467835);
     }
 
     #[test]
     fn test_puzzle_2() {
-        let (grid, grid_width) = parse(include_str!("../../data/day3/input.txt"));
+        let (grid, grid_width) = parse(include_str!("../../data/day3/input.txt")).unwrap();
 
         assert_eq!(puzzle_2(&grid, grid_width), 80403602);
     }
@@ -0,0 +1,111 @@
+use std::collections::HashSet;
+use crate::parse::{ParseError, Parser};
+use crate::solution::{Solution, SolveError};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input<'a> = Vec<Card>;
+    type Output = i32;
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(raw_input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<i32, SolveError> {
+        Ok(puzzle_1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Option<i32>, SolveError> {
+        Ok(Some(puzzle_2(input)))
+    }
+}
+
+pub struct Card {
+    winning_numbers: HashSet<i32>,
+    received_numbers: HashSet<i32>,
+}
+
+fn parse(raw_input: &str) -> Result<Vec<Card>, ParseError> {
+    let parser = Parser::new(raw_input);
+
+    parser
+        .lines()
+        .map(|line| {
+            let (_, numbers) = parser.split_once(line, ":")?;
+            let (winning, received) = parser.split_once(numbers, "|")?;
+
+            Ok(Card {
+                winning_numbers: HashSet::from_iter(parser.numbers(winning)?),
+                received_numbers: HashSet::from_iter(parser.numbers(received)?),
+            })
+        })
+        .collect()
+}
+
+fn puzzle_1(data: &[Card]) -> i32 {
+    get_correct_counts(data)
+        .iter()
+        .filter_map(|count| match count {
+            0 => None,
+            _ => Some(2i32.pow((count - 1) as u32)),
+        })
+        .sum()
+}
+
+fn puzzle_2(data: &[Card]) -> i32 {
+    let mut card_counts = vec![1; data.len()];
+
+    get_correct_counts(data)
+        .iter()
+        .enumerate()
+        .for_each(|(i, count)| {
+            for j in i + 1 ..= i + count {
+                card_counts[j] += card_counts[i]
+            }
+        });
+
+    card_counts.iter().sum()
+}
+
+fn get_correct_counts(data: &[Card]) -> Vec<usize> {
+    data
+        .iter()
+        .map(|card| card.winning_numbers.intersection(&card.received_numbers).count())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle_1_example() {
+        let input = parse(include_str!("../../data/day4/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), 13);
+    }
+
+    #[test]
+    fn test_puzzle_1() {
+        let input = parse(include_str!("../../data/day4/input.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), 21158);
+    }
+
+    #[test]
+    fn test_puzzle_2_example() {
+        let input = parse(include_str!("../../data/day4/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), 30);
+    }
+
+    #[test]
+    fn test_puzzle_2() {
+        let input = parse(include_str!("../../data/day4/input.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), 6050769);
+    }
+}
\ No newline at end of file
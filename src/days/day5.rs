@@ -0,0 +1,152 @@
+use itertools::{Itertools};
+use crate::interval::{Interval, IntervalSet};
+use crate::parse::{ParseError, Parser};
+use crate::solution::{Solution, SolveError};
+
+type Maps = Vec<Vec<(i64, i64, i64)>>;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input<'a> = (Vec<i64>, Maps);
+    type Output = i64;
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(raw_input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<i64, SolveError> {
+        Ok(puzzle_1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Option<i64>, SolveError> {
+        Ok(Some(puzzle_2(input)))
+    }
+}
+
+fn parse(raw_input: &str) -> Result<(Vec<i64>, Maps), ParseError>
+{
+    let parser = Parser::new(raw_input);
+    let (first, rest) = parser.split_once(raw_input, "\n\n")?;
+    let (_, seed_numbers) = parser.split_once(first, ":")?;
+
+    let seeds = parser.numbers::<i64>(seed_numbers)?;
+
+    // The second part reads the seeds as pairs of a start and a length
+    if seeds.is_empty() || seeds.len() % 2 != 0 {
+        return Err(parser.error(seed_numbers.trim(), "pairs of seed numbers"));
+    }
+
+    if seeds.chunks(2).any(|pair| pair[1] <= 0) {
+        return Err(parser.error(seed_numbers.trim(), "ranges of at least one seed"));
+    }
+
+    let maps = rest
+        .split("\n\n")
+        .map(|section| {
+            section
+                .lines()
+                .skip(1)
+                .map(|line| parser
+                    .numbers::<i64>(line)?
+                    .into_iter()
+                    .collect_tuple()
+                    .ok_or_else(|| parser.error(line, "three numbers"))
+                )
+                .collect::<Result<Vec<(i64, i64, i64)>, ParseError>>()
+        })
+        .collect::<Result<Maps, ParseError>>()?;
+
+    Ok((seeds, maps))
+}
+
+fn puzzle_1((seeds, maps): &(Vec<i64>, Maps)) -> i64 {
+    seeds
+        .iter()
+        .map(|seed| {
+            maps
+                .iter()
+                .fold(*seed, | value, ranges | {
+                    ranges
+                        .iter()
+                        .find(|&&(_, source_range_start, range_length)| {
+                            Interval::new(source_range_start, source_range_start + range_length).contains(value)
+                        })
+                        .map_or(value, |&(destination_range_start, source_range_start, _)| {
+                            value + destination_range_start - source_range_start
+                        })
+                })
+        })
+        .min()
+        .unwrap()
+}
+
+fn puzzle_2((seeds, maps): &(Vec<i64>, Maps)) -> i64 {
+    let values = seeds
+        .chunks(2)
+        .map(|c| Interval::new(c[0], c[0] + c[1]))
+        .collect::<IntervalSet>();
+
+    maps
+        .iter()
+        .fold(values, |values, ranges| {
+            let mut mapped = IntervalSet::new();
+
+            for &(destination_range_start, source_range_start, range_length) in ranges {
+                let source = IntervalSet::from_iter([Interval::new(source_range_start, source_range_start + range_length)]);
+
+                mapped = mapped.union(&values.intersection(&source).shift(destination_range_start - source_range_start));
+            }
+
+            let sources = ranges
+                .iter()
+                .map(|&(_, source_range_start, range_length)| Interval::new(source_range_start, source_range_start + range_length))
+                .collect::<IntervalSet>();
+
+            mapped.union(&values.difference(&sources))
+        })
+        .min()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle_1_example() {
+        let input = parse(include_str!("../../data/day5/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), 35);
+    }
+
+    #[test]
+    fn test_puzzle_1() {
+        let input = parse(include_str!("../../data/day5/input.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), 178159714);
+    }
+
+    #[test]
+    fn test_puzzle_2_example() {
+        let input = parse(include_str!("../../data/day5/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), 46);
+    }
+
+    #[test]
+    fn test_puzzle_2() {
+        let input = parse(include_str!("../../data/day5/input.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), 100165128);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse("seeds: 1 2 3\n\nmap:\n1 2 3").unwrap_err().expected, "pairs of seed numbers");
+        assert_eq!(parse("seeds:\n\nmap:\n1 2 3").unwrap_err().expected, "pairs of seed numbers");
+        assert_eq!(parse("seeds: 1 2 3 0\n\nmap:\n1 2 3").unwrap_err().expected, "ranges of at least one seed");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,105 @@
+use float_next_after::NextAfter;
+use crate::parse::{ParseError, Parser};
+use crate::solution::{Solution, SolveError};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input<'a> = Vec<(i64, i64)>;
+    type Output = i64;
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(raw_input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<i64, SolveError> {
+        Ok(puzzle_1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Option<i64>, SolveError> {
+        Ok(Some(puzzle_2(input)))
+    }
+}
+
+fn parse(raw_input: &str) -> Result<Vec<(i64, i64)>, ParseError> {
+    let parser = Parser::new(raw_input);
+    let mut lines = parser.lines();
+
+    let time_line = lines.next().unwrap_or(parser.end());
+    let distance_line = lines.next().unwrap_or(parser.end());
+
+    let times = parser.numbers::<i64>(parser.split_once(time_line, "Time:")?.1)?;
+    let distances = parser.numbers::<i64>(parser.split_once(distance_line, "Distance:")?.1)?;
+
+    if times.len() != distances.len() {
+        return Err(parser.error(distance_line, format!("{} distances", times.len())));
+    }
+
+    Ok(times.into_iter().zip(distances).collect())
+}
+
+fn puzzle_1(data: &[(i64, i64)]) -> i64 {
+    data
+        .iter()
+        .map(|&(time_limit, target_distance)| count_winning_options(time_limit, target_distance))
+        .product()
+}
+
+fn puzzle_2(data: &[(i64, i64)]) -> i64 {
+    // The spaces between the numbers are bad kerning, there is only a single race
+    let (time_limit, target_distance) = data
+        .iter()
+        .fold((String::new(), String::new()), |(time_limit, target_distance), (time, distance)| {
+            (format!("{time_limit}{time}"), format!("{target_distance}{distance}"))
+        });
+
+    count_winning_options(time_limit.parse().unwrap(), target_distance.parse().unwrap())
+}
+
+fn count_winning_options(time_limit: i64, target_distance: i64) -> i64 {
+    // Limits where the resulting distance will be larger than the target distance
+    let min_hold_time_limit: f64 = 0.5 * ((time_limit as f64) - ((time_limit.pow(2) - 4 * target_distance) as f64).sqrt());
+    let max_hold_time_limit: f64 = 0.5 * ((time_limit as f64) + ((time_limit.pow(2) - 4 * target_distance) as f64).sqrt());
+
+    // Find the outer integers that are within these float limits
+    let min_hold_time = min_hold_time_limit.next_after(f64::INFINITY).ceil() as i64;
+    let max_hold_time = max_hold_time_limit.next_after(f64::NEG_INFINITY).floor() as i64;
+
+    max_hold_time - min_hold_time + 1
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle_1_example() {
+        let input = parse(include_str!("../../data/day6/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), 288);
+    }
+
+    #[test]
+    fn test_puzzle_1() {
+        let input = parse(include_str!("../../data/day6/input.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), 2269432);
+    }
+
+    #[test]
+    fn test_puzzle_2_example() {
+        let input = parse(include_str!("../../data/day6/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), 71503);
+    }
+
+    #[test]
+    fn test_puzzle_2() {
+        let input = parse(include_str!("../../data/day6/input.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), 35865985);
+    }
+}
\ No newline at end of file
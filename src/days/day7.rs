@@ -1,20 +1,42 @@
 use itertools::{Itertools};
+use crate::parse::{ParseError, Parser};
+use crate::solution::{Solution, SolveError};
 
-fn main() {
-    let input = parse(include_str!("../../data/day7/input.txt"));
+pub struct Day7;
 
-    puzzle_1(&input);
-    puzzle_2(&input);
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input<'a> = Vec<(&'a str, i64)>;
+    type Output = i64;
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(raw_input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<i64, SolveError> {
+        Ok(puzzle_1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Option<i64>, SolveError> {
+        Ok(Some(puzzle_2(input)))
+    }
 }
 
-fn parse(raw_input: &str) -> Vec<(&str, i64)>
+fn parse(raw_input: &str) -> Result<Vec<(&str, i64)>, ParseError>
 {
-    raw_input
+    let parser = Parser::new(raw_input);
+
+    parser
         .lines()
         .map(|l| {
-            let (cards, bet) = l.split_once(' ').unwrap();
+            let (cards, bet) = parser.split_once(l, " ")?;
+
+            if cards.len() != 5 || !cards.chars().all(|c| "AKQJT98765432".contains(c)) {
+                return Err(parser.error(cards, "five cards of `AKQJT98765432`"));
+            }
 
-            (cards, bet.parse::<i64>().unwrap())
+            Ok((cards, parser.number::<i64>(bet)?))
         })
         .collect()
 }
@@ -111,28 +133,28 @@ mod tests {
 
     #[test]
     fn test_puzzle_1_example() {
-        let input = parse(include_str!("../../data/day7/input_example.txt"));
+        let input = parse(include_str!("../../data/day7/input_example.txt")).unwrap();
 
         assert_eq!(puzzle_1(&input), 6440);
     }
 
     #[test]
     fn test_puzzle_1() {
-        let input = parse(include_str!("../../data/day7/input.txt"));
+        let input = parse(include_str!("../../data/day7/input.txt")).unwrap();
 
         assert_eq!(puzzle_1(&input), 248836197);
     }
 
     #[test]
     fn test_puzzle_2_example() {
-        let input = parse(include_str!("../../data/day7/input_example.txt"));
+        let input = parse(include_str!("../../data/day7/input_example.txt")).unwrap();
 
         assert_eq!(puzzle_2(&input), 5905);
     }
 
     #[test]
     fn test_puzzle_2() {
-        let input = parse(include_str!("../../data/day7/input.txt"));
+        let input = parse(include_str!("../../data/day7/input.txt")).unwrap();
 
         assert_eq!(puzzle_2(&input), 251195607);
     }
@@ -0,0 +1,177 @@
+use std::iter;
+use itertools::Itertools;
+use log::debug;
+use crate::cycle::brent;
+use crate::graph::Graph;
+use crate::number_theory::lcm_all;
+use crate::parse::{ParseError, Parser};
+use crate::solution::{Solution, SolveError};
+
+/// Every node has an edge to its left and its right node, in that order.
+type Network<'a> = (Vec<usize>, Graph<'a>);
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input<'a> = Network<'a>;
+    type Output = i128;
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(raw_input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<i128, SolveError> {
+        Ok(puzzle_1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Option<i128>, SolveError> {
+        puzzle_2(input).map(Some)
+    }
+}
+
+fn parse(raw_input: &str) -> Result<Network<'_>, ParseError> {
+    let parser = Parser::new(raw_input);
+    let (first_line, rest) = parser.split_once(raw_input, "\n\n")?;
+
+    let directions = first_line
+        .char_indices()
+        .map(|(i, c)| match c {
+            'L' => Ok(0),
+            'R' => Ok(1),
+            _ => Err(parser.error(&first_line[i..i + c.len_utf8()], "`L` or `R`")),
+        })
+        .collect::<Result<Vec<usize>, ParseError>>()?;
+
+    let mut graph = Graph::directed();
+
+    for l in rest.lines() {
+        let (node, options) = parser.split_once(l, " = ")?;
+        let (left, right) = parser.split_once(parser.strip(options, "(", ")")?, ", ")?;
+
+        graph.add_edge(node, left);
+        graph.add_edge(node, right);
+    }
+
+    // Every node that can be reached must also be defined
+    if let Some(unknown) = (0..graph.node_count()).find(|&n| graph.neighbors(n).is_empty()) {
+        return Err(parser.error(graph.name(unknown), "a defined node"));
+    }
+
+    Ok((
+        directions,
+        graph,
+    ))
+}
+
+fn puzzle_1((directions, graph): &Network) -> i128 {
+    let end = graph.id("ZZZ").unwrap();
+
+    directions
+        .iter()
+        .cycle()
+        .scan((graph.id("AAA").unwrap(), 0), | (node, steps), &direction | {
+            *node = graph.neighbors(*node)[direction];
+            *steps += 1;
+            Some((*node, *steps))
+        })
+        .find(|&(node, _) | node == end)
+        .unwrap()
+        .1
+}
+
+fn puzzle_2((directions, graph): &Network) -> Result<i128, SolveError> {
+    let ending_periods = (0..graph.node_count())
+        .filter(|&n| graph.name(n).ends_with('A'))
+        .map(|n| {
+            // A state is a node and the position in the instructions, every path ends up looping
+            let step = |&(node, instruction_index): &(usize, usize)| {
+                (graph.neighbors(node)[directions[instruction_index]], (instruction_index + 1) % directions.len())
+            };
+            let (start, cycle_length) = brent((n, 0), &step);
+
+            let ending_steps: Vec<usize> = iter::successors(Some((n, 0)), |state| Some(step(state)))
+                .take(start + cycle_length)
+                .positions(|(node, _)| graph.name(node).ends_with('Z'))
+                .collect();
+            let Some(&period) = ending_steps.first() else {
+                return Err(SolveError::new(format!("the path from {} never reaches an ending", graph.name(n))));
+            };
+
+            // The answer is the least common multiple of the steps to the first ending only when the path is on an
+            // ending after every multiple of those steps and never in between, which the loop repeats forever
+            if start > period || cycle_length % period != 0 || !ending_steps.iter().copied().eq((period..start + cycle_length).step_by(period)) {
+                return Err(SolveError::new(format!(
+                    "the path from {} reaches an ending after {ending_steps:?} steps in a loop of {cycle_length}, not every {period} steps",
+                    graph.name(n),
+                )));
+            }
+
+            debug!("Path from {} reaches an ending every {period} steps", graph.name(n));
+            Ok(period as i128)
+        })
+        .collect::<Result<Vec<i128>, SolveError>>()?;
+
+    lcm_all(ending_periods).ok_or_else(|| SolveError::new("the number of steps does not fit in an i128"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle_1_example() {
+        let input = parse(include_str!("../../data/day8/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), 6);
+    }
+
+    #[test]
+    fn test_puzzle_1() {
+        let input = parse(include_str!("../../data/day8/input.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), 18023);
+    }
+
+    #[test]
+    fn test_puzzle_2_example() {
+        let input = parse(include_str!("../../data/day8/input_example_2.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), Ok(6));
+    }
+
+    #[test]
+    fn test_puzzle_2_ending_twice_per_loop() {
+        // With two instructions the states only repeat after 6 steps, but the path is on `11Z` every 3 steps
+        let input = parse("LR\n\n11A = (11B, 11B)\n11B = (11C, 11C)\n11C = (11Z, 11Z)\n11Z = (11B, 11B)").unwrap();
+
+        assert_eq!(puzzle_2(&input), Ok(3));
+    }
+
+    #[test]
+    fn test_puzzle_2() {
+        let input = parse(include_str!("../../data/day8/input.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), Ok(14449445933179));
+    }
+
+    #[test]
+    fn test_puzzle_2_no_answer() {
+        let never_ending = parse("L\n\n11A = (11B, 11B)\n11B = (11A, 11A)").unwrap();
+        assert!(puzzle_2(&never_ending).is_err());
+
+        // `11Z` comes after 1 step, but then only every 2 steps
+        let not_aligned = parse("L\n\n11A = (11Z, 11Z)\n11Z = (11B, 11B)\n11B = (11Z, 11Z)").unwrap();
+        assert!(puzzle_2(&not_aligned).is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse("LRX\n\nAAA = (AAA, AAA)").err().unwrap();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (1, 3, "`L` or `R`"));
+
+        let error = parse("LR\n\nAAA = (AAA, ZZZ)").err().unwrap();
+        assert_eq!((error.line, error.column, error.found.as_str()), (3, 13, "ZZZ"));
+    }
+}
\ No newline at end of file
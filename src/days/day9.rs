@@ -0,0 +1,88 @@
+use crate::parse::{ParseError, Parser};
+use crate::polynomial::DifferenceTable;
+use crate::solution::{Solution, SolveError};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input<'a> = Vec<Vec<i32>>;
+    type Output = i32;
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(raw_input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<i32, SolveError> {
+        Ok(puzzle_1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Option<i32>, SolveError> {
+        Ok(Some(puzzle_2(input)))
+    }
+}
+
+fn parse(raw_input: &str) -> Result<Vec<Vec<i32>>, ParseError>
+{
+    let parser = Parser::new(raw_input);
+
+    parser
+        .lines()
+        .map(|l| match parser.numbers::<i32>(l)? {
+            sequence if sequence.is_empty() => Err(parser.error(l, "a number")),
+            sequence => Ok(sequence),
+        })
+        .collect()
+}
+
+fn puzzle_1(data: &[Vec<i32>]) -> i32 {
+    data
+        .iter()
+        .map(|sequence| polynomial(sequence).evaluate(sequence.len() as i128) as i32)
+        .sum()
+}
+
+fn puzzle_2(data: &[Vec<i32>]) -> i32 {
+    data
+        .iter()
+        .map(|sequence| polynomial(sequence).evaluate(-1) as i32)
+        .sum()
+}
+
+fn polynomial(sequence: &[i32]) -> DifferenceTable {
+    DifferenceTable::new(0, &sequence.iter().map(|&v| v as i128).collect::<Vec<i128>>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle_1_example() {
+        let input = parse(include_str!("../../data/day9/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), 114);
+    }
+
+    #[test]
+    fn test_puzzle_1() {
+        let input = parse(include_str!("../../data/day9/input.txt")).unwrap();
+
+        assert_eq!(puzzle_1(&input), 1974232246);
+    }
+
+    #[test]
+    fn test_puzzle_2_example() {
+        let input = parse(include_str!("../../data/day9/input_example.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), 2);
+    }
+
+    #[test]
+    fn test_puzzle_2() {
+        let input = parse(include_str!("../../data/day9/input.txt")).unwrap();
+
+        assert_eq!(puzzle_2(&input), 928);
+    }
+}
\ No newline at end of file
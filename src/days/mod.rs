@@ -0,0 +1,78 @@
+pub mod day0;
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+use crate::animation::Recorder;
+use crate::bench::{benchmark, Measurements};
+use crate::parse::ParseError;
+use crate::render::Canvas;
+use crate::solution::{animate, render, solve, Part, Solution, SolutionError};
+
+pub struct Day {
+    pub number: u8,
+    pub run: fn(Part, &str) -> Result<Option<String>, SolutionError>,
+    pub bench: fn(&str, usize) -> Result<Measurements, SolutionError>,
+    pub render: fn(Part, &str) -> Result<Option<Canvas>, ParseError>,
+    pub animate: fn(Part, &str) -> Result<Option<Recorder>, ParseError>,
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Self {
+        Day { number: S::DAY, run: solve::<S>, bench: benchmark::<S>, render: render::<S>, animate: animate::<S> }
+    }
+}
+
+pub static DAYS: &[Day] = &[
+    Day::of::<day1::Day1>(),
+    Day::of::<day2::Day2>(),
+    Day::of::<day3::Day3>(),
+    Day::of::<day4::Day4>(),
+    Day::of::<day5::Day5>(),
+    Day::of::<day6::Day6>(),
+    Day::of::<day7::Day7>(),
+    Day::of::<day8::Day8>(),
+    Day::of::<day9::Day9>(),
+    Day::of::<day10::Day10>(),
+    Day::of::<day11::Day11>(),
+    Day::of::<day12::Day12>(),
+    Day::of::<day13::Day13>(),
+    Day::of::<day14::Day14>(),
+    Day::of::<day15::Day15>(),
+    Day::of::<day16::Day16>(),
+    Day::of::<day17::Day17>(),
+    Day::of::<day18::Day18>(),
+    Day::of::<day19::Day19>(),
+    Day::of::<day20::Day20>(),
+    Day::of::<day21::Day21>(),
+    Day::of::<day22::Day22>(),
+    Day::of::<day23::Day23>(),
+    Day::of::<day24::Day24>(),
+    Day::of::<day25::Day25>(),
+];
+
+pub fn find_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
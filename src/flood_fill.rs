@@ -0,0 +1,138 @@
+use std::collections::VecDeque;
+use crate::Coordinate;
+use crate::bit_grid::BitGrid;
+use crate::grid::Grid;
+
+/// The cells reachable from the seeds by orthogonal steps that `can_step(from, to)` allows. Seeds are always included.
+pub fn flood_fill<T>(
+    grid: &Grid<T>,
+    seeds: impl IntoIterator<Item = Coordinate>,
+    mut can_step: impl FnMut(Coordinate, Coordinate) -> bool,
+) -> BitGrid {
+    let mut filled = BitGrid::new(grid.height(), grid.width());
+    let mut stack: Vec<Coordinate> = seeds.into_iter().filter(|&seed| filled.insert(seed)).collect();
+
+    while let Some(coordinate) = stack.pop() {
+        for (_, next) in grid.von_neumann_compass(coordinate) {
+            if !filled.get(next) && can_step(coordinate, next) {
+                filled.insert(next);
+                stack.push(next);
+            }
+        }
+    }
+
+    filled
+}
+
+/// The fewest steps from any seed to every cell, like `flood_fill`. `None` for cells that can not be reached.
+pub fn distance_map<T>(
+    grid: &Grid<T>,
+    seeds: impl IntoIterator<Item = Coordinate>,
+    mut can_step: impl FnMut(Coordinate, Coordinate) -> bool,
+) -> Grid<Option<usize>> {
+    let mut distances = Grid::new(grid.height(), grid.width(), None);
+    let mut queue = VecDeque::new();
+
+    for seed in seeds {
+        if distances[seed].is_none() {
+            distances[seed] = Some(0);
+            queue.push_back((seed, 0));
+        }
+    }
+
+    while let Some((coordinate, distance)) = queue.pop_front() {
+        for (_, next) in grid.von_neumann_compass(coordinate) {
+            if distances[next].is_none() && can_step(coordinate, next) {
+                distances[next] = Some(distance + 1);
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    distances
+}
+
+/// A group of orthogonally connected cells, the bounding box runs from `top_left` to `bottom_right` inclusive.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Region {
+    pub size: usize,
+    pub top_left: Coordinate,
+    pub bottom_right: Coordinate,
+}
+
+/// Splits the grid into regions, neighboring cells are in the same region when `same_region` holds for their values.
+/// Returns the index of the region of every cell and the regions, in the order of their first cell row by row.
+pub fn label_regions<T>(grid: &Grid<T>, mut same_region: impl FnMut(&T, &T) -> bool) -> (Grid<usize>, Vec<Region>) {
+    let mut labels: Grid<Option<usize>> = Grid::new(grid.height(), grid.width(), None);
+    let mut regions = Vec::new();
+
+    for start in grid.coordinates() {
+        if labels[start].is_some() {
+            continue;
+        }
+
+        let label = regions.len();
+        let mut region = Region { size: 0, top_left: start, bottom_right: start };
+        let mut stack = vec![start];
+        labels[start] = Some(label);
+
+        while let Some(coordinate @ (y, x)) = stack.pop() {
+            region.size += 1;
+            region.top_left = (region.top_left.0.min(y), region.top_left.1.min(x));
+            region.bottom_right = (region.bottom_right.0.max(y), region.bottom_right.1.max(x));
+
+            for (_, next) in grid.von_neumann_compass(coordinate) {
+                if labels[next].is_none() && same_region(&grid[coordinate], &grid[next]) {
+                    labels[next] = Some(label);
+                    stack.push(next);
+                }
+            }
+        }
+
+        regions.push(region);
+    }
+
+    (labels.map(|label| label.unwrap()), regions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        Grid::from_lines("..#..\n..#..\n###..\n....#")
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let grid = example();
+        let filled = flood_fill(&grid, [(0, 0)], |_, to| grid[to] == '.');
+
+        assert_eq!(filled.to_string(), "##...\n##...\n.....\n.....");
+        assert_eq!(flood_fill(&grid, [(0, 0), (0, 4)], |_, to| grid[to] == '.').count_ones(), 14);
+    }
+
+    #[test]
+    fn test_distance_map() {
+        let grid = example();
+        let distances = distance_map(&grid, [(0, 4)], |_, to| grid[to] == '.');
+
+        assert_eq!(distances[(3, 0)], Some(7));
+        assert_eq!(distances[(0, 0)], None);
+        assert_eq!(distances[(3, 4)], None);
+        assert_eq!(distances.iter().filter_map(|(_, &d)| d).max(), Some(7));
+    }
+
+    #[test]
+    fn test_label_regions() {
+        let grid = example();
+        let (labels, regions) = label_regions(&grid, |a, b| a == b);
+
+        assert_eq!(labels.row(0), [0, 0, 1, 2, 2]);
+        assert_eq!(labels[(3, 4)], 3);
+        assert_eq!(regions[0], Region { size: 4, top_left: (0, 0), bottom_right: (1, 1) });
+        assert_eq!(regions[1], Region { size: 5, top_left: (0, 0), bottom_right: (2, 2) });
+        assert_eq!(regions[2], Region { size: 10, top_left: (0, 0), bottom_right: (3, 4) });
+        assert_eq!(regions.len(), 4);
+    }
+}
@@ -0,0 +1,318 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Write;
+
+/// Numbers names in the order they are first seen, so graphs can work with indices instead of strings.
+#[derive(Clone, Debug, Default)]
+pub struct Interner<'a> {
+    ids: HashMap<&'a str, usize>,
+    names: Vec<&'a str>,
+}
+
+impl<'a> Interner<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn intern(&mut self, name: &'a str) -> usize {
+        *self.ids.entry(name).or_insert_with(|| {
+            self.names.push(name);
+            self.names.len() - 1
+        })
+    }
+
+    pub fn id(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: usize) -> &'a str {
+        self.names[id]
+    }
+
+    pub fn names(&self) -> &[&'a str] {
+        &self.names
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+/// A graph of named nodes stored as adjacency lists. Neighbors keep the order their edges were added in, and adding
+/// the same edge twice gives parallel edges.
+#[derive(Clone, Debug)]
+pub struct Graph<'a> {
+    nodes: Interner<'a>,
+    adjacency: Vec<Vec<usize>>,
+    directed: bool,
+}
+
+impl<'a> Graph<'a> {
+    pub fn directed() -> Self {
+        Self { nodes: Interner::new(), adjacency: Vec::new(), directed: true }
+    }
+
+    /// Every edge can be followed both ways.
+    pub fn undirected() -> Self {
+        Self { nodes: Interner::new(), adjacency: Vec::new(), directed: false }
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    pub fn add_node(&mut self, name: &'a str) -> usize {
+        let id = self.nodes.intern(name);
+
+        if id == self.adjacency.len() {
+            self.adjacency.push(Vec::new());
+        }
+
+        id
+    }
+
+    /// Adds both nodes when they are new and returns their ids.
+    pub fn add_edge(&mut self, from: &'a str, to: &'a str) -> (usize, usize) {
+        let (from, to) = (self.add_node(from), self.add_node(to));
+
+        self.adjacency[from].push(to);
+        if !self.directed {
+            self.adjacency[to].push(from);
+        }
+
+        (from, to)
+    }
+
+    /// Removes one edge between the nodes, returns whether there was one.
+    pub fn remove_edge(&mut self, from: usize, to: usize) -> bool {
+        let Some(position) = self.adjacency[from].iter().position(|&n| n == to) else {
+            return false;
+        };
+        self.adjacency[from].remove(position);
+
+        if !self.directed {
+            let position = self.adjacency[to].iter().position(|&n| n == from).unwrap();
+            self.adjacency[to].remove(position);
+        }
+
+        true
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    /// Undirected edges are counted once.
+    pub fn edge_count(&self) -> usize {
+        let count = self.adjacency.iter().map(Vec::len).sum::<usize>();
+
+        if self.directed { count } else { count / 2 }
+    }
+
+    pub fn id(&self, name: &str) -> Option<usize> {
+        self.nodes.id(name)
+    }
+
+    pub fn name(&self, id: usize) -> &'a str {
+        self.nodes.name(id)
+    }
+
+    pub fn names(&self) -> &[&'a str] {
+        self.nodes.names()
+    }
+
+    pub fn neighbors(&self, id: usize) -> &[usize] {
+        &self.adjacency[id]
+    }
+
+    /// The nodes reachable from `start` in breadth-first order.
+    pub fn bfs(&self, start: usize) -> Vec<usize> {
+        self.bfs_distances(start).1
+    }
+
+    /// The number of edges on the shortest path to every node, `None` for nodes that can not be reached, together
+    /// with the reachable nodes in breadth-first order.
+    pub fn bfs_distances(&self, start: usize) -> (Vec<Option<usize>>, Vec<usize>) {
+        let mut distances = vec![None; self.node_count()];
+        let mut order = Vec::new();
+        let mut queue = VecDeque::from([start]);
+        distances[start] = Some(0);
+
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+
+            for &next in &self.adjacency[node] {
+                if distances[next].is_none() {
+                    distances[next] = distances[node].map(|d| d + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        (distances, order)
+    }
+
+    /// The nodes reachable from `start` in depth-first preorder, neighbors are visited in the order of their edges.
+    pub fn dfs(&self, start: usize) -> Vec<usize> {
+        let mut visited = vec![false; self.node_count()];
+        let mut order = Vec::new();
+        let mut stack = vec![start];
+
+        while let Some(node) = stack.pop() {
+            if visited[node] {
+                continue;
+            }
+
+            visited[node] = true;
+            order.push(node);
+            stack.extend(self.adjacency[node].iter().rev().filter(|&&n| !visited[n]));
+        }
+
+        order
+    }
+
+    /// The groups of nodes that are connected, ignoring the direction of edges. Every group is sorted and the groups
+    /// are ordered by their lowest id.
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        let mut undirected = self.adjacency.clone();
+        if self.directed {
+            for (from, neighbors) in self.adjacency.iter().enumerate() {
+                for &to in neighbors {
+                    undirected[to].push(from);
+                }
+            }
+        }
+
+        let mut component_of = vec![None; self.node_count()];
+        let mut components = Vec::new();
+
+        for start in 0..self.node_count() {
+            if component_of[start].is_some() {
+                continue;
+            }
+
+            let mut component = Vec::new();
+            let mut stack = vec![start];
+            component_of[start] = Some(components.len());
+
+            while let Some(node) = stack.pop() {
+                component.push(node);
+
+                for &next in &undirected[node] {
+                    if component_of[next].is_none() {
+                        component_of[next] = Some(components.len());
+                        stack.push(next);
+                    }
+                }
+            }
+
+            component.sort_unstable();
+            components.push(component);
+        }
+
+        components
+    }
+
+    /// Graphviz DOT source with quoted node names. Every edge is written once, parallel edges as separate lines.
+    pub fn to_dot(&self) -> String {
+        let (keyword, arrow) = if self.directed { ("digraph", "->") } else { ("graph", "--") };
+        let mut dot = format!("{keyword} {{\n");
+
+        let mut has_edges: Vec<bool> = self.adjacency.iter().map(|neighbors| !neighbors.is_empty()).collect();
+        self.adjacency.iter().flatten().for_each(|&to| has_edges[to] = true);
+
+        for (from, neighbors) in self.adjacency.iter().enumerate() {
+            if !has_edges[from] {
+                writeln!(dot, "  {};", dot_id(self.name(from))).unwrap();
+            }
+
+            // An undirected edge is in the neighbors of both its nodes, a loop twice in those of its node
+            let mut loops = 0;
+            let edges = neighbors.iter().filter(|&&to| {
+                if to == from && !self.directed {
+                    loops += 1;
+                    return loops % 2 == 1;
+                }
+
+                self.directed || from < to
+            });
+
+            for &to in edges {
+                writeln!(dot, "  {} {arrow} {};", dot_id(self.name(from)), dot_id(self.name(to))).unwrap();
+            }
+        }
+
+        dot.push('}');
+        dot
+    }
+}
+
+fn dot_id(name: &str) -> String {
+    format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interner() {
+        let mut interner = Interner::new();
+
+        assert_eq!(interner.intern("jqt"), 0);
+        assert_eq!(interner.intern("rhn"), 1);
+        assert_eq!(interner.intern("jqt"), 0);
+        assert_eq!((interner.id("rhn"), interner.id("xhk"), interner.name(1)), (Some(1), None, "rhn"));
+    }
+
+    #[test]
+    fn test_traversals() {
+        let mut graph = Graph::directed();
+        graph.add_edge("a", "b");
+        graph.add_edge("a", "c");
+        graph.add_edge("b", "d");
+        graph.add_edge("c", "d");
+        graph.add_node("e");
+
+        assert_eq!(graph.bfs(0), vec![0, 1, 2, 3]);
+        assert_eq!(graph.dfs(0), vec![0, 1, 3, 2]);
+        assert_eq!(graph.bfs_distances(0).0, vec![Some(0), Some(1), Some(1), Some(2), None]);
+        assert_eq!(graph.connected_components(), vec![vec![0, 1, 2, 3], vec![4]]);
+        assert_eq!(graph.bfs(3), vec![3]);
+    }
+
+    #[test]
+    fn test_undirected() {
+        let mut graph = Graph::undirected();
+        graph.add_edge("a", "b");
+        graph.add_edge("b", "c");
+        graph.add_edge("d", "e");
+
+        assert_eq!((graph.node_count(), graph.edge_count()), (5, 3));
+        assert_eq!(graph.connected_components(), vec![vec![0, 1, 2], vec![3, 4]]);
+
+        assert!(graph.remove_edge(2, 1));
+        assert!(!graph.remove_edge(2, 1));
+        assert_eq!(graph.connected_components(), vec![vec![0, 1], vec![2], vec![3, 4]]);
+        assert_eq!(graph.to_dot(), "graph {\n  \"a\" -- \"b\";\n  \"c\";\n  \"d\" -- \"e\";\n}");
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let mut graph = Graph::undirected();
+        graph.add_edge("a", "a");
+        graph.add_edge("a", "b\"c");
+        graph.add_edge("b\"c", "a");
+
+        assert_eq!(graph.to_dot(), "graph {\n  \"a\" -- \"a\";\n  \"a\" -- \"b\\\"c\";\n  \"a\" -- \"b\\\"c\";\n}");
+
+        let mut graph = Graph::directed();
+        graph.add_edge("a", "a");
+        graph.add_edge("a\\", "a");
+
+        assert_eq!(graph.to_dot(), "digraph {\n  \"a\" -> \"a\";\n  \"a\\\\\" -> \"a\";\n}");
+    }
+}
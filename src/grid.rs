@@ -0,0 +1,443 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+use std::fmt;
+use crate::{Compass, Coordinate, Direction};
+
+/// A rectangular grid stored row by row, indexed with `(y, x)` coordinates.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    height: usize,
+    width: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(height: usize, width: usize, value: T) -> Self where T: Clone {
+        Self { cells: vec![value; height * width], height, width }
+    }
+
+    /// Panics when the rows are not all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(rows.iter().all(|row| row.len() == width), "all rows of a grid must have the same length");
+
+        Self { cells: rows.into_iter().flatten().collect(), height, width }
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn contains(&self, (y, x): Coordinate) -> bool {
+        y < self.height && x < self.width
+    }
+
+    pub fn get(&self, coordinate: Coordinate) -> Option<&T> {
+        self.contains(coordinate).then(|| &self.cells[coordinate.0 * self.width + coordinate.1])
+    }
+
+    pub fn get_mut(&mut self, coordinate: Coordinate) -> Option<&mut T> {
+        let width = self.width;
+
+        self.contains(coordinate).then(|| &mut self.cells[coordinate.0 * width + coordinate.1])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + '_ {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> + '_ {
+        self.cells.chunks_mut(self.width.max(1)).take(self.height)
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        assert!(x < self.width, "column {x} is outside a grid of width {}", self.width);
+
+        (0..self.height).map(move |y| &self.cells[y * self.width + x])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// All coordinates, row by row.
+    pub fn coordinates(&self) -> impl Iterator<Item = Coordinate> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (y, x)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coordinate, &T)> + '_ {
+        self.coordinates().zip(&self.cells)
+    }
+
+    /// The coordinate of the first cell, row by row, that matches the predicate.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Coordinate> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|i| (i / self.width, i % self.width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { cells: self.cells.iter().map(f).collect(), height: self.height, width: self.width }
+    }
+
+    pub fn transpose(&self) -> Self where T: Clone {
+        Self {
+            cells: (0..self.width).flat_map(|x| self.column(x).cloned()).collect(),
+            height: self.width,
+            width: self.height,
+        }
+    }
+
+    pub fn reverse_rows(&self) -> Self where T: Clone {
+        Self {
+            cells: self.rows().flat_map(|row| row.iter().rev().cloned()).collect(),
+            height: self.height,
+            width: self.width,
+        }
+    }
+
+    pub fn reverse_columns(&self) -> Self where T: Clone {
+        Self {
+            cells: self.rows().rev().flatten().cloned().collect(),
+            height: self.height,
+            width: self.width,
+        }
+    }
+
+    /// Looks at the grid turned or mirrored, without copying it.
+    pub fn view(&self, transform: Transform) -> GridView<'_, T> {
+        GridView { grid: self, transform }
+    }
+
+    /// Turns or mirrors the grid without allocating.
+    pub fn apply(&mut self, transform: Transform) {
+        match transform {
+            Transform::Identity => {},
+            Transform::Rotate90 => {
+                self.transpose_in_place();
+                self.apply(Transform::FlipHorizontal);
+            },
+            Transform::Rotate180 => self.cells.reverse(),
+            Transform::Rotate270 => {
+                self.transpose_in_place();
+                self.apply(Transform::FlipVertical);
+            },
+            Transform::FlipHorizontal => self.rows_mut().for_each(<[T]>::reverse),
+            Transform::FlipVertical => {
+                for y in 0..self.height / 2 {
+                    let (top, bottom) = self.cells.split_at_mut((self.height - 1 - y) * self.width);
+                    top[y * self.width..(y + 1) * self.width].swap_with_slice(&mut bottom[..self.width]);
+                }
+            },
+            Transform::Transpose => self.transpose_in_place(),
+            Transform::AntiTranspose => {
+                self.cells.reverse();
+                self.transpose_in_place();
+            },
+        }
+    }
+
+    fn transpose_in_place(&mut self) {
+        let (height, width) = (self.height, self.width);
+
+        if height == width {
+            for y in 0..height {
+                for x in y + 1..width {
+                    self.cells.swap(y * width + x, x * width + y);
+                }
+            }
+        } else {
+            // The cell at index i of the transposed grid comes from `source(i)`, follow every cycle of that permutation
+            // once, starting from its smallest index
+            let source = |i: usize| (i % height) * width + i / height;
+
+            for start in 0..self.cells.len() {
+                let mut i = source(start);
+                while i > start {
+                    i = source(i);
+                }
+
+                if i < start {
+                    continue;
+                }
+
+                let mut i = start;
+                while source(i) != start {
+                    self.cells.swap(i, source(i));
+                    i = source(i);
+                }
+            }
+        }
+
+        (self.height, self.width) = (width, height);
+    }
+
+    /// The coordinates along the edge, clockwise from the top left corner. Corners are included twice.
+    pub fn boundary_coordinates(&self) -> Vec<Coordinate> {
+        if self.height == 0 || self.width == 0 {
+            return Vec::new();
+        }
+
+        let top = (0..self.width).map(|x| (0, x));
+        let right = (0..self.height).map(|y| (y, self.width - 1));
+        let bottom = (0..self.width).map(|x| (self.height - 1, x));
+        let left = (0..self.height).map(|y| (y, 0));
+
+        top.chain(right).chain(bottom).chain(left).collect()
+    }
+
+    /// The up to 8 surrounding coordinates inside the grid.
+    pub fn moore_neighborhood(&self, (y, x): Coordinate) -> Vec<Coordinate> {
+        [
+            (y.wrapping_sub(1), x.wrapping_sub(1)),
+            (y.wrapping_sub(1), x),
+            (y.wrapping_sub(1), x + 1),
+            (y, x.wrapping_sub(1)),
+            (y, x + 1),
+            (y + 1, x.wrapping_sub(1)),
+            (y + 1, x),
+            (y + 1, x + 1),
+        ]
+            .into_iter()
+            .filter(|&c| self.contains(c))
+            .collect()
+    }
+
+    /// The up to 4 orthogonally adjacent coordinates inside the grid, with the direction to reach them.
+    pub fn von_neumann_compass(&self, coordinate: Coordinate) -> Compass {
+        Direction::ORTHOGONAL
+            .into_iter()
+            .filter_map(|direction| Some((direction, self.coordinate_in_direction(coordinate, direction)?)))
+            .collect()
+    }
+
+    pub fn coordinate_in_direction(&self, coordinate: Coordinate, direction: Direction) -> Option<Coordinate> {
+        self.coordinate_steps_in_direction(coordinate, 1, direction)
+    }
+
+    pub fn coordinate_steps_in_direction(&self, (y, x): Coordinate, steps: usize, direction: Direction) -> Option<Coordinate> {
+        let delta = direction.unit_vector() * steps as i64;
+        let next_coordinate = (y.wrapping_add_signed(delta.y as isize), x.wrapping_add_signed(delta.x as isize));
+
+        self.contains(next_coordinate).then_some(next_coordinate)
+    }
+}
+
+impl Grid<char> {
+    /// One row of characters per line of the text. Panics when the lines are not all the same length.
+    pub fn from_lines(text: &str) -> Self {
+        Self::from_rows(text.lines().map(|line| line.chars().collect()).collect())
+    }
+}
+
+/// The 8 ways to turn or mirror a grid. Rotations are clockwise, `FlipHorizontal` mirrors left and right and
+/// `AntiTranspose` mirrors along the diagonal from the top right to the bottom left corner.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Transform {
+    Identity,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    FlipHorizontal,
+    FlipVertical,
+    Transpose,
+    AntiTranspose,
+}
+
+impl Transform {
+    /// Whether height and width trade places.
+    pub fn swaps_axes(self) -> bool {
+        matches!(self, Self::Rotate90 | Self::Rotate270 | Self::Transpose | Self::AntiTranspose)
+    }
+}
+
+/// A grid seen through a `Transform`, coordinates are those of the transformed grid.
+#[derive(Clone, Copy, Debug)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    transform: Transform,
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn height(&self) -> usize {
+        if self.transform.swaps_axes() { self.grid.width } else { self.grid.height }
+    }
+
+    pub fn width(&self) -> usize {
+        if self.transform.swaps_axes() { self.grid.height } else { self.grid.width }
+    }
+
+    /// The coordinate in the underlying grid.
+    fn source(&self, (y, x): Coordinate) -> Coordinate {
+        let (height, width) = (self.height(), self.width());
+
+        match self.transform {
+            Transform::Identity => (y, x),
+            Transform::Rotate90 => (width - 1 - x, y),
+            Transform::Rotate180 => (height - 1 - y, width - 1 - x),
+            Transform::Rotate270 => (x, height - 1 - y),
+            Transform::FlipHorizontal => (y, width - 1 - x),
+            Transform::FlipVertical => (height - 1 - y, x),
+            Transform::Transpose => (x, y),
+            Transform::AntiTranspose => (width - 1 - x, height - 1 - y),
+        }
+    }
+
+    pub fn get(&self, coordinate: Coordinate) -> Option<&'a T> {
+        let (y, x) = coordinate;
+
+        (y < self.height() && x < self.width()).then(|| &self.grid[self.source(coordinate)])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = &'a T> + '_> + '_ {
+        (0..self.height()).map(move |y| (0..self.width()).map(move |x| &self.grid[self.source((y, x))]))
+    }
+
+    pub fn to_grid(&self) -> Grid<T> where T: Clone {
+        Grid { cells: self.rows().flatten().cloned().collect(), height: self.height(), width: self.width() }
+    }
+}
+
+impl<T> Index<Coordinate> for GridView<'_, T> {
+    type Output = T;
+
+    fn index(&self, coordinate: Coordinate) -> &Self::Output {
+        self.get(coordinate).unwrap_or_else(|| panic!("coordinate {coordinate:?} is outside the grid view"))
+    }
+}
+
+impl<T> Index<Coordinate> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (y, x): Coordinate) -> &Self::Output {
+        assert!(x < self.width, "coordinate ({y}, {x}) is outside a grid of width {}", self.width);
+
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<Coordinate> for Grid<T> {
+    fn index_mut(&mut self, (y, x): Coordinate) -> &mut Self::Output {
+        assert!(x < self.width, "coordinate ({y}, {x}) is outside a grid of width {}", self.width);
+
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// Every cell is written without separators, rows are separated by newlines.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        Grid::from_lines("abc\ndef")
+    }
+
+    #[test]
+    fn test_indexing() {
+        let mut grid = example();
+
+        assert_eq!((grid.height(), grid.width()), (2, 3));
+        assert_eq!(grid[(1, 0)], 'd');
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.position(|&c| c == 'e'), Some((1, 1)));
+
+        grid[(1, 0)] = 'x';
+        assert_eq!(grid.row(1), ['x', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+    }
+
+    #[test]
+    fn test_transformations() {
+        let grid = example();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.reverse_rows().to_string(), "cba\nfed");
+        assert_eq!(grid.reverse_columns().to_string(), "def\nabc");
+        assert_eq!(grid.map(|c| c.to_ascii_uppercase()).to_string(), "ABC\nDEF");
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = example();
+        let rotated = grid.view(Transform::Rotate90);
+
+        assert_eq!((rotated.height(), rotated.width()), (3, 2));
+        assert_eq!(rotated[(0, 0)], 'd');
+        assert_eq!(rotated.get((0, 2)), None);
+        assert_eq!(rotated.to_grid().to_string(), "da\neb\nfc");
+        assert_eq!(grid.view(Transform::Rotate180).to_grid().to_string(), "fed\ncba");
+        assert_eq!(grid.view(Transform::Rotate270).to_grid().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.view(Transform::FlipHorizontal).to_grid(), grid.reverse_rows());
+        assert_eq!(grid.view(Transform::FlipVertical).to_grid(), grid.reverse_columns());
+        assert_eq!(grid.view(Transform::Transpose).to_grid(), grid.transpose());
+        assert_eq!(grid.view(Transform::AntiTranspose).to_grid().to_string(), "fc\neb\nda");
+    }
+
+    #[test]
+    fn test_apply() {
+        let transforms = [
+            Transform::Identity,
+            Transform::Rotate90,
+            Transform::Rotate180,
+            Transform::Rotate270,
+            Transform::FlipHorizontal,
+            Transform::FlipVertical,
+            Transform::Transpose,
+            Transform::AntiTranspose,
+        ];
+        let square = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+        let wide = Grid::from_rows(vec![(0..5).collect(), (5..10).collect(), (10..15).collect()]);
+        let tall = Grid::from_rows((0..7).map(|y| (0..4).map(|x| y * 4 + x).collect()).collect());
+
+        for grid in [square, wide, tall] {
+            for transform in transforms {
+                let mut applied = grid.clone();
+                applied.apply(transform);
+
+                assert_eq!(applied, grid.view(transform).to_grid(), "{transform:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = example();
+
+        assert_eq!(grid.moore_neighborhood((0, 0)), vec![(0, 1), (1, 0), (1, 1)]);
+        assert_eq!(grid.von_neumann_compass((1, 2)), vec![(Direction::North, (0, 2)), (Direction::West, (1, 1))]);
+        assert_eq!(grid.coordinate_steps_in_direction((0, 0), 2, Direction::East), Some((0, 2)));
+        assert_eq!(grid.coordinate_steps_in_direction((0, 0), 3, Direction::East), None);
+        assert_eq!(grid.coordinate_in_direction((0, 1), Direction::SouthWest), Some((1, 0)));
+        assert_eq!(grid.boundary_coordinates().len(), 10);
+    }
+}
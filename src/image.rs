@@ -0,0 +1,209 @@
+use std::io::Write;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Rgb {
+    pub const BLACK: Self = Self::new(0, 0, 0);
+    pub const WHITE: Self = Self::new(255, 255, 255);
+    pub const GRAY: Self = Self::new(128, 128, 128);
+    pub const DARK_GRAY: Self = Self::new(64, 64, 64);
+    pub const RED: Self = Self::new(220, 50, 47);
+    pub const GREEN: Self = Self::new(80, 200, 80);
+    pub const DARK_GREEN: Self = Self::new(20, 90, 40);
+    pub const BLUE: Self = Self::new(38, 139, 210);
+    pub const YELLOW: Self = Self::new(240, 200, 40);
+    pub const ORANGE: Self = Self::new(240, 130, 30);
+
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Blends linearly from this color at `fraction` 0 to the other at 1.
+    pub fn mix(self, other: Self, fraction: f64) -> Self {
+        let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * fraction.clamp(0.0, 1.0)).round() as u8;
+
+        Self::new(channel(self.r, other.r), channel(self.g, other.g), channel(self.b, other.b))
+    }
+}
+
+/// The file formats an image can be encoded in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+}
+
+impl ImageFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Png => "png",
+        }
+    }
+}
+
+/// An RGB image stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    pixels: Vec<Rgb>,
+    height: usize,
+    width: usize,
+}
+
+impl Image {
+    pub fn new(height: usize, width: usize, background: Rgb) -> Self {
+        Self { pixels: vec![background; height * width], height, width }
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn pixels(&self) -> &[Rgb] {
+        &self.pixels
+    }
+
+    pub fn get(&self, y: usize, x: usize) -> Rgb {
+        self.pixels[y * self.width + x]
+    }
+
+    pub fn set(&mut self, y: usize, x: usize, color: Rgb) {
+        self.pixels[y * self.width + x] = color;
+    }
+
+    /// Colors the square of `size` pixels with its top left corner at `(y, x)`.
+    pub fn fill_square(&mut self, y: usize, x: usize, size: usize, color: Rgb) {
+        for row in y..(y + size).min(self.height) {
+            self.pixels[row * self.width + x..row * self.width + (x + size).min(self.width)].fill(color);
+        }
+    }
+
+    pub fn encode(&self, format: ImageFormat) -> Vec<u8> {
+        match format {
+            ImageFormat::Ppm => self.to_ppm(),
+            ImageFormat::Png => self.to_png(),
+        }
+    }
+
+    /// Binary PPM (P6), readable by most image viewers.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut ppm = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        ppm.extend(self.pixels.iter().flat_map(|p| [p.r, p.g, p.b]));
+
+        ppm
+    }
+
+    /// PNG with 8 bit RGB pixels. The pixel data is stored without compression.
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+
+        let mut header = Vec::new();
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        header.extend([8, 2, 0, 0, 0]); // Bit depth, RGB, default compression, filtering and no interlacing
+        write_chunk(&mut png, b"IHDR", &header);
+
+        // Every row starts with filter type 0, no filtering
+        let raw: Vec<u8> = self.pixels
+            .chunks(self.width.max(1))
+            .take(self.height)
+            .flat_map(|row| std::iter::once(0).chain(row.iter().flat_map(|p| [p.r, p.g, p.b])))
+            .collect();
+        write_chunk(&mut png, b"IDAT", &zlib_stored(&raw));
+        write_chunk(&mut png, b"IEND", &[]);
+
+        png
+    }
+}
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.write_all(&(data.len() as u32).to_be_bytes()).unwrap();
+    png.write_all(kind).unwrap();
+    png.write_all(data).unwrap();
+    png.write_all(&crc32(kind.iter().chain(data)).to_be_bytes()).unwrap();
+}
+
+/// A zlib stream of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut zlib = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+
+    if blocks.peek().is_none() {
+        zlib.extend([1, 0, 0, 0xff, 0xff]);
+    }
+
+    while let Some(block) = blocks.next() {
+        let length = block.len() as u16;
+
+        zlib.push(blocks.peek().is_none() as u8);
+        zlib.extend(length.to_le_bytes());
+        zlib.extend((!length).to_le_bytes());
+        zlib.extend(block);
+    }
+
+    zlib.extend(adler32(data).to_be_bytes());
+    zlib
+}
+
+pub(crate) fn crc32<'a>(bytes: impl IntoIterator<Item = &'a u8>) -> u32 {
+    !bytes.into_iter().fold(u32::MAX, |crc, &byte| {
+        (0..8).fold(crc ^ byte as u32, |crc, _| if crc & 1 == 1 { (crc >> 1) ^ 0xedb88320 } else { crc >> 1 })
+    })
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (a, b) = bytes.iter().fold((1u32, 0u32), |(a, b), &byte| {
+        let a = (a + byte as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"123456789"), 0xcbf43926);
+        assert_eq!(crc32(b"IEND"), 0xae426082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e60398);
+    }
+
+    #[test]
+    fn test_ppm() {
+        let mut image = Image::new(1, 2, Rgb::BLACK);
+        image.set(0, 1, Rgb::new(1, 2, 3));
+
+        assert_eq!(image.to_ppm(), b"P6\n2 1\n255\n\x00\x00\x00\x01\x02\x03");
+    }
+
+    #[test]
+    fn test_png() {
+        let mut image = Image::new(3, 2, Rgb::WHITE);
+        image.fill_square(1, 0, 5, Rgb::RED);
+        let png = image.to_png();
+
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[8..29], b"\x00\x00\x00\x0dIHDR\x00\x00\x00\x02\x00\x00\x00\x03\x08\x02\x00\x00\x00");
+        assert_eq!(png[29..33], crc32(&png[12..29]).to_be_bytes());
+        assert_eq!(&png[png.len() - 12..], b"\x00\x00\x00\x00IEND\xae\x42\x60\x82");
+
+        // Three rows of a filter byte and two pixels, in a single stored block
+        let idat_length = u32::from_be_bytes(png[33..37].try_into().unwrap());
+        assert_eq!(idat_length, 2 + 5 + 21 + 4);
+        assert_eq!(&png[41..48], [0x78, 0x01, 1, 21, 0, !21, 0xff]);
+        assert_eq!(&png[48..55], [0, 255, 255, 255, 255, 255, 255]);
+        assert_eq!(&png[55..62], [0, 220, 50, 47, 220, 50, 47]);
+    }
+}
@@ -0,0 +1,112 @@
+use std::fmt::{Display, Formatter};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::{fmt, fs, io};
+
+pub const DEFAULT_DATA_DIR: &str = "data";
+
+/// Where the puzzle input of a day is read from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputSource {
+    /// `<data dir>/day<N>/input.txt`
+    DataDir(PathBuf),
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Parses a command line argument, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn describe(&self, day: u8) -> String {
+        match self {
+            InputSource::DataDir(data_dir) => input_path(data_dir, day).display().to_string(),
+            InputSource::File(path) => path.display().to_string(),
+            InputSource::Stdin => String::from("stdin"),
+        }
+    }
+
+    pub fn read(&self, day: u8) -> Result<String, InputError> {
+        let raw_input = match self {
+            InputSource::DataDir(data_dir) => fs::read_to_string(input_path(data_dir, day)),
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut raw_input = String::new();
+                io::stdin().read_to_string(&mut raw_input).map(|_| raw_input)
+            },
+        };
+
+        raw_input
+            .map(|raw_input| normalize(&raw_input))
+            .map_err(|error| InputError { day, location: self.describe(day), error })
+    }
+}
+
+impl Default for InputSource {
+    fn default() -> Self {
+        InputSource::DataDir(PathBuf::from(DEFAULT_DATA_DIR))
+    }
+}
+
+pub fn input_path(data_dir: &Path, day: u8) -> PathBuf {
+    data_dir.join(format!("day{day}")).join("input.txt")
+}
+
+/// The parsers expect the input without a trailing newline, as it is stored in `data/`.
+fn normalize(raw_input: &str) -> String {
+    raw_input
+        .trim_end_matches(['\n', '\r'])
+        .to_string()
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub day: u8,
+    pub location: String,
+    pub error: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.error.kind() {
+            io::ErrorKind::NotFound => write!(f, "input for day {} not found at `{}`", self.day, self.location),
+            _ => write!(f, "could not read input for day {} from `{}`: {}", self.day, self.location, self.error),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_path() {
+        assert_eq!(input_path(Path::new("data"), 7), PathBuf::from("data/day7/input.txt"));
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("1\n2\n"), "1\n2");
+        assert_eq!(normalize("1\r\n2\r\n\n"), "1\r\n2");
+        assert_eq!(normalize("1\n2"), "1\n2");
+    }
+
+    #[test]
+    fn test_read_missing_file() {
+        let source = InputSource::DataDir(PathBuf::from("does/not/exist"));
+        let error = source.read(3).unwrap_err();
+
+        assert_eq!(error.to_string(), "input for day 3 not found at `does/not/exist/day3/input.txt`");
+    }
+}
@@ -0,0 +1,276 @@
+use std::fmt::{Display, Formatter};
+use std::fmt;
+
+/// The integers from `start` up to but not including `end`, empty when `end <= start`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub const fn new(start: i64, end: i64) -> Self {
+        Self { start, end }
+    }
+
+    /// The integers from `first` up to and including `last`.
+    pub const fn inclusive(first: i64, last: i64) -> Self {
+        Self::new(first, last + 1)
+    }
+
+    pub fn len(&self) -> i64 {
+        (self.end - self.start).max(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// `None` when the intervals do not overlap.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Some(Self::new(self.start.max(other.start), self.end.min(other.end))).filter(|i| !i.is_empty())
+    }
+
+    /// The values below `value` and the values from `value` upwards, `None` for a part that is empty.
+    pub fn split_at(&self, value: i64) -> (Option<Self>, Option<Self>) {
+        let below = Self::new(self.start, self.end.min(value));
+        let above = Self::new(self.start.max(value), self.end);
+
+        (Some(below).filter(|i| !i.is_empty()), Some(above).filter(|i| !i.is_empty()))
+    }
+
+    pub fn shift(&self, offset: i64) -> Self {
+        Self::new(self.start + offset, self.end + offset)
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {})", self.start, self.end)
+    }
+}
+
+/// A set of integers stored as sorted intervals that neither overlap nor touch.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The number of integers in the set.
+    pub fn len(&self) -> i64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(|i| i.end - 1)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let index = self.intervals.partition_point(|i| i.end <= value);
+
+        self.intervals.get(index).is_some_and(|i| i.contains(value))
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+
+        // Merge with every interval that overlaps or touches the new one
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, i| Interval::new(merged.start.min(i.start), merged.end.max(i.end)));
+
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        other.intervals.iter().for_each(|&i| union.insert(i));
+
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intersection = Vec::new();
+        let (mut a, mut b) = (0, 0);
+
+        while a < self.intervals.len() && b < other.intervals.len() {
+            intersection.extend(self.intervals[a].intersection(&other.intervals[b]));
+
+            if self.intervals[a].end < other.intervals[b].end {
+                a += 1;
+            } else {
+                b += 1;
+            }
+        }
+
+        Self { intervals: intersection }
+    }
+
+    /// The values of this set that are not in the other.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = Vec::new();
+
+        for &interval in &self.intervals {
+            let mut rest = Some(interval);
+
+            for removed in other.intervals.iter().skip_while(|r| r.end <= interval.start) {
+                let Some(remaining) = rest else { break };
+                if removed.start >= remaining.end {
+                    break;
+                }
+
+                let (below, _) = remaining.split_at(removed.start);
+                difference.extend(below);
+                rest = remaining.split_at(removed.end).1;
+            }
+
+            difference.extend(rest);
+        }
+
+        Self { intervals: difference }
+    }
+
+    /// The values below `value` and the values from `value` upwards.
+    pub fn split_at(&self, value: i64) -> (Self, Self) {
+        let (below, above) = self.intervals.iter().map(|i| i.split_at(value)).unzip::<_, _, Vec<_>, Vec<_>>();
+
+        (
+            Self { intervals: below.into_iter().flatten().collect() },
+            Self { intervals: above.into_iter().flatten().collect() },
+        )
+    }
+
+    pub fn shift(&self, offset: i64) -> Self {
+        Self { intervals: self.intervals.iter().map(|i| i.shift(offset)).collect() }
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut set = Self::new();
+        iter.into_iter().for_each(|i| set.insert(i));
+
+        set
+    }
+}
+
+/// An axis-aligned box of integer points, an interval in each of `N` dimensions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cuboid<const N: usize> {
+    pub ranges: [Interval; N],
+}
+
+impl<const N: usize> Cuboid<N> {
+    pub const fn new(ranges: [Interval; N]) -> Self {
+        Self { ranges }
+    }
+
+    /// The number of points in the box.
+    pub fn volume(&self) -> i64 {
+        self.ranges.iter().map(Interval::len).product()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.iter().any(Interval::is_empty)
+    }
+
+    pub fn contains(&self, point: [i64; N]) -> bool {
+        self.ranges.iter().zip(point).all(|(range, value)| range.contains(value))
+    }
+
+    /// `None` when the boxes do not overlap.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut ranges = self.ranges;
+
+        for (range, other) in ranges.iter_mut().zip(&other.ranges) {
+            *range = range.intersection(other)?;
+        }
+
+        Some(Self { ranges })
+    }
+
+    /// Cuts the box in two along a dimension, like `Interval::split_at`.
+    pub fn split_at(&self, dimension: usize, value: i64) -> (Option<Self>, Option<Self>) {
+        let (below, above) = self.ranges[dimension].split_at(value);
+        let with_range = |range: Interval| {
+            let mut ranges = self.ranges;
+            ranges[dimension] = range;
+            Self { ranges }
+        };
+
+        (below.map(with_range), above.map(with_range))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interval() {
+        let interval = Interval::inclusive(79, 92);
+
+        assert_eq!(interval.len(), 14);
+        assert!(interval.contains(92) && !interval.contains(93));
+        assert_eq!(interval.intersection(&Interval::new(90, 100)), Some(Interval::new(90, 93)));
+        assert_eq!(interval.intersection(&Interval::new(93, 100)), None);
+        assert_eq!(interval.split_at(80), (Some(Interval::new(79, 80)), Some(Interval::new(80, 93))));
+        assert_eq!(interval.split_at(79), (None, Some(interval)));
+    }
+
+    #[test]
+    fn test_interval_set() {
+        let set: IntervalSet = [Interval::new(10, 20), Interval::new(0, 5), Interval::new(5, 7), Interval::new(30, 40)].into_iter().collect();
+
+        assert_eq!(set.intervals(), [Interval::new(0, 7), Interval::new(10, 20), Interval::new(30, 40)]);
+        assert_eq!(set.len(), 27);
+        assert!(set.contains(15) && !set.contains(25) && !set.contains(7));
+
+        let other: IntervalSet = [Interval::new(3, 12), Interval::new(35, 50)].into_iter().collect();
+
+        assert_eq!(set.union(&other).intervals(), [Interval::new(0, 20), Interval::new(30, 50)]);
+        assert_eq!(set.intersection(&other).intervals(), [Interval::new(3, 7), Interval::new(10, 12), Interval::new(35, 40)]);
+        assert_eq!(set.difference(&other).intervals(), [Interval::new(0, 3), Interval::new(12, 20), Interval::new(30, 35)]);
+
+        let (below, above) = set.split_at(15);
+        assert_eq!((below.len(), above.len()), (12, 15));
+        assert_eq!((above.min(), above.max()), (Some(15), Some(39)));
+    }
+
+    #[test]
+    fn test_cuboid() {
+        let cuboid = Cuboid::new([Interval::inclusive(1, 4000); 4]);
+        let (below, above) = cuboid.split_at(2, 1351);
+
+        assert_eq!(cuboid.volume(), 4000i64.pow(4));
+        assert_eq!(below.unwrap().volume() + above.unwrap().volume(), cuboid.volume());
+        assert!(below.unwrap().contains([1, 1, 1350, 1]) && !below.unwrap().contains([1, 1, 1351, 1]));
+        assert_eq!(below.unwrap().intersection(&above.unwrap()), None);
+    }
+}
@@ -1,49 +1,31 @@
-use std::cmp::Ordering;
-use std::mem::swap;
-use std::slice::Iter;
-use crate::Direction::{East, North, South, West};
-
-pub fn moore_neighborhood((y, x): (usize, usize), height: usize, width: usize) -> Vec<(usize, usize)> {
-    [
-        (y.wrapping_sub(1), x.wrapping_sub(1)),
-        (y.wrapping_sub(1), x),
-        (y.wrapping_sub(1), x + 1),
-        (y, x.wrapping_sub(1)),
-        (y, x + 1),
-        (y + 1, x.wrapping_sub(1)),
-        (y + 1, x),
-        (y + 1, x + 1),
-    ]
-        .into_iter()
-        .filter(|&(ny, nx)| ny < height && nx < width)
-        .collect()
-}
-
-pub fn transpose<T: Copy>(matrix: &[Vec<T>]) -> Vec<Vec<T>> {
-    let mut row_iters: Vec<Iter<T>> = matrix
-        .iter()
-        .map(|r| r.iter())
-        .collect();
-
-    (0..matrix.first().unwrap().len())
-        .map(|_| row_iters
-            .iter_mut()
-            .map(|it| *it.next().unwrap())
-            .collect::<Vec<T>>()
-        )
-        .collect()
-}
-
-pub fn reverse_rows<T: Copy>(matrix: &[Vec<T>]) -> Vec<Vec<T>> {
-    matrix
-        .iter()
-        .map(|row| row.clone().into_iter().rev().collect::<Vec<T>>())
-        .collect()
-}
+pub mod animation;
+pub mod answers;
+pub mod bench;
+pub mod bit_grid;
+pub mod client;
+pub mod cycle;
+pub mod days;
+pub mod flood_fill;
+pub mod graph;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod interval;
+pub mod number_theory;
+pub mod parse;
+pub mod point;
+pub mod polygon;
+pub mod polynomial;
+pub mod render;
+pub mod report;
+pub mod scaffold;
+pub mod shortest_path;
+pub mod solution;
 
-pub fn reverse_columns<T: Copy>(matrix: &[Vec<T>]) -> Vec<Vec<T>> {
-    transpose(&reverse_rows(&transpose(matrix)))
-}
+use std::cmp::Ordering;
+use std::ops::Sub;
+use crate::Direction::{East, North, NorthEast, NorthWest, South, SouthEast, SouthWest, West};
+use crate::point::Vector;
 
 pub fn count_different_elements<T: Eq>(a: &[T], b: &[T]) -> usize {
     a
@@ -53,124 +35,184 @@ pub fn count_different_elements<T: Eq>(a: &[T], b: &[T]) -> usize {
         .count()
 }
 
-pub fn get_boundary_coordinates(height: usize, width: usize) -> Vec<(usize, usize)> {
-    let top: Vec<(usize, usize)> = vec![0; width]
-        .into_iter()
-        .zip(0..width)
-        .collect();
-
-    let bottom: Vec<(usize, usize)> = vec![height - 1; width]
-        .into_iter()
-        .zip(0..width)
-        .collect();
+pub type Coordinate = (usize, usize);
+pub type Compass = Vec<(Direction, Coordinate)>;
 
-    let left: Vec<(usize, usize)> = (0..height)
-        .zip(vec![0; height])
-        .collect();
+/// The orthogonal directions keep the discriminants 0 to 3, so they can index arrays of four.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Direction {
+    North = 0,
+    East = 1,
+    South = 2,
+    West = 3,
+    NorthEast = 4,
+    SouthEast = 5,
+    SouthWest = 6,
+    NorthWest = 7,
+}
 
-    let right: Vec<(usize, usize)> = (0..height)
-        .zip(vec![width - 1; height])
-        .collect();
+impl Direction {
+    /// All directions, clockwise from north.
+    pub const ALL: [Direction; 8] = [North, NorthEast, East, SouthEast, South, SouthWest, West, NorthWest];
+    pub const ORTHOGONAL: [Direction; 4] = [North, East, South, West];
+    pub const DIAGONAL: [Direction; 4] = [NorthEast, SouthEast, SouthWest, NorthWest];
+
+    /// The direction from the first to the second coordinate, `None` when they are equal or not on a horizontal,
+    /// vertical or diagonal line.
+    pub fn from_coordinates<T: Ord + Copy + Sub<Output = T>>(&(y0, x0): &(T, T), &(y1, x1): &(T, T)) -> Option<Self> {
+        let distance = |a: T, b: T| if a < b { b - a } else { a - b };
+
+        match (y0.cmp(&y1), x0.cmp(&x1)) {
+            (Ordering::Equal, Ordering::Equal) => None,
+            (Ordering::Less, Ordering::Equal) => Some(South),
+            (Ordering::Greater, Ordering::Equal) => Some(North),
+            (Ordering::Equal, Ordering::Less) => Some(East),
+            (Ordering::Equal, Ordering::Greater) => Some(West),
+            _ if distance(y0, y1) != distance(x0, x1) => None,
+            (Ordering::Less, Ordering::Less) => Some(SouthEast),
+            (Ordering::Less, Ordering::Greater) => Some(SouthWest),
+            (Ordering::Greater, Ordering::Less) => Some(NorthEast),
+            (Ordering::Greater, Ordering::Greater) => Some(NorthWest),
+        }
+    }
 
-    Vec::from_iter([top, right, bottom, left].into_iter().flatten())
-}
+    pub fn is_diagonal(&self) -> bool {
+        Self::DIAGONAL.contains(self)
+    }
 
-pub type Coordinate = (usize, usize);
-pub type Compass = Vec<(Direction, Coordinate)>;
+    /// Turns clockwise by the given number of quarter turns, counterclockwise when negative.
+    pub fn turn(&self, quarter_turns: i32) -> Self {
+        let index = Self::ALL.iter().position(|d| d == self).unwrap() as i32;
 
-#[derive(Clone, Copy, Eq, PartialEq, Hash)]
-pub enum Direction { North = 0, East = 1, South = 2, West = 3 }
-impl Direction {
-    pub fn from_coordinates<T: Ord>((y0, x0): &(T, T), (y1, x1): &(T, T)) -> Self {
-        match (y0.cmp(y1), x0.cmp(x1)) {
-            (Ordering::Less, Ordering::Equal) => Ok(South),
-            (Ordering::Greater, Ordering::Equal) => Ok(North),
-            (Ordering::Equal, Ordering::Less) => Ok(East),
-            (Ordering::Equal, Ordering::Greater) => Ok(West),
-            _ => Err("Unexpected direction"),
-        }.expect("")
+        Self::ALL[(index + 2 * quarter_turns).rem_euclid(8) as usize]
     }
 
     pub fn opposite(&self) -> Self {
-        match self {
-            North => South,
-            East => West,
-            South => North,
-            West => East,
-        }
+        self.turn(2)
     }
 
     pub fn rotate_clockwise(&self) -> Self {
-        match self {
-            North => East,
-            East => South,
-            South => West,
-            West => North,
-        }
+        self.turn(1)
     }
 
     pub fn rotate_counterclockwise(&self) -> Self {
+        self.turn(-1)
+    }
+
+    /// The step to a neighbor in this direction, north is towards a lower `y`.
+    pub fn unit_vector(&self) -> Vector {
         match self {
-            North => West,
-            East => North,
-            South => East,
-            West => South,
+            North => Vector::new(-1, 0),
+            NorthEast => Vector::new(-1, 1),
+            East => Vector::new(0, 1),
+            SouthEast => Vector::new(1, 1),
+            South => Vector::new(1, 0),
+            SouthWest => Vector::new(1, -1),
+            West => Vector::new(0, -1),
+            NorthWest => Vector::new(-1, -1),
         }
     }
 }
 
-pub fn von_neumann_compass((y, x): (usize, usize), height: usize, width: usize) -> Compass {
-    [
-        (North, (y.wrapping_sub(1), x)),
-        (East, (y, x + 1)),
-        (South, (y + 1, x)),
-        (West, (y, x.wrapping_sub(1))),
-    ]
-        .into_iter()
-        .filter(|&(_, (ny, nx))| ny < height && nx < width)
-        .collect()
-}
+/// Accepts `U`/`D`/`L`/`R`, `N`/`E`/`S`/`W` and the arrows `^`/`v`/`<`/`>`, the invalid character is returned.
+impl TryFrom<char> for Direction {
+    type Error = char;
 
-pub fn coordinate_in_direction(coordinate: &Coordinate, direction: &Direction, height: usize, width: usize) -> Option<Coordinate> {
-    let next_coordinate = match direction {
-        North => (coordinate.0.wrapping_sub(1), coordinate.1),
-        East => (coordinate.0, coordinate.1 + 1),
-        South => (coordinate.0 + 1, coordinate.1),
-        West => (coordinate.0, coordinate.1.wrapping_sub(1)),
-    };
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Orthogonal::try_from(c).map(Direction::from)
+    }
+}
 
-    if next_coordinate.0 < height && next_coordinate.1 < width { Some(next_coordinate) } else { None }
+/// A direction without the diagonals, for what only moves horizontally or vertically. The discriminants are those of
+/// the `Direction` with the same name.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Orthogonal {
+    North = 0,
+    East = 1,
+    South = 2,
+    West = 3,
 }
 
-pub fn coordinate_steps_in_direction(coordinate: &Coordinate, steps: usize, direction: &Direction, height: usize, width: usize) -> Option<Coordinate> {
-    let next_coordinate = match direction {
-        North => (coordinate.0.wrapping_sub(steps), coordinate.1),
-        East => (coordinate.0, coordinate.1 + steps),
-        South => (coordinate.0 + steps, coordinate.1),
-        West => (coordinate.0, coordinate.1.wrapping_sub(steps)),
-    };
+impl Orthogonal {
+    /// All orthogonal directions, clockwise from north.
+    pub const ALL: [Orthogonal; 4] = [Orthogonal::North, Orthogonal::East, Orthogonal::South, Orthogonal::West];
+
+    pub fn opposite(&self) -> Self {
+        Self::ALL[(*self as usize + 2) % 4]
+    }
 
-    if next_coordinate.0 < height && next_coordinate.1 < width { Some(next_coordinate) } else { None }
+    /// The digit notation that counts clockwise from east, `0` to `3`.
+    pub fn from_digit(c: char) -> Option<Self> {
+        c.to_digit(4).map(|d| Self::ALL[(d as usize + 1) % 4])
+    }
 }
 
-pub fn lcm(first: i128, second: i128) -> i128 {
-    first * second / gcd(first, second)
+impl From<Orthogonal> for Direction {
+    fn from(direction: Orthogonal) -> Self {
+        Direction::ORTHOGONAL[direction as usize]
+    }
 }
 
-pub fn gcd(first: i128, second: i128) -> i128 {
-    let mut max = first;
-    let mut min = second;
-    if min > max {
-        swap(&mut max, &mut min);
+/// Fails with the direction when it is diagonal.
+impl TryFrom<Direction> for Orthogonal {
+    type Error = Direction;
+
+    fn try_from(direction: Direction) -> Result<Self, Self::Error> {
+        Self::ALL.get(direction as usize).copied().ok_or(direction)
     }
+}
 
-    loop {
-        let res = max % min;
-        if res == 0 {
-            return min;
+/// Accepts the same characters as `Direction`.
+impl TryFrom<char> for Orthogonal {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'U' | 'N' | '^' => Ok(Orthogonal::North),
+            'R' | 'E' | '>' => Ok(Orthogonal::East),
+            'D' | 'S' | 'v' => Ok(Orthogonal::South),
+            'L' | 'W' | '<' => Ok(Orthogonal::West),
+            _ => Err(c),
         }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction_from_coordinates() {
+        assert_eq!(Direction::from_coordinates(&(3, 3), &(0, 3)), Some(North));
+        assert_eq!(Direction::from_coordinates(&(3usize, 3usize), &(5, 1)), Some(SouthWest));
+        assert_eq!(Direction::from_coordinates(&(3, 3), &(5, 2)), None);
+        assert_eq!(Direction::from_coordinates(&(3, 3), &(3, 3)), None);
+    }
+
+    #[test]
+    fn test_direction_turns() {
+        assert_eq!(North.turn(1), East);
+        assert_eq!(North.turn(-1), West);
+        assert_eq!(NorthEast.turn(5), SouthEast);
+        assert_eq!(SouthWest.opposite(), NorthEast);
+        assert!(Direction::ALL.iter().all(|d| d.unit_vector() == -d.opposite().unit_vector()));
+    }
+
+    #[test]
+    fn test_direction_try_from_char() {
+        assert_eq!("URDL".chars().map(Direction::try_from).collect::<Result<Vec<_>, _>>(), Ok(Direction::ORTHOGONAL.to_vec()));
+        assert_eq!("^>v<".chars().map(Direction::try_from).collect::<Result<Vec<_>, _>>(), Ok(Direction::ORTHOGONAL.to_vec()));
+        assert_eq!(Direction::try_from('x'), Err('x'));
+    }
 
-        max = min;
-        min = res;
+    #[test]
+    fn test_orthogonal() {
+        assert_eq!(Orthogonal::ALL.map(Direction::from), Direction::ORTHOGONAL);
+        assert_eq!(Direction::ORTHOGONAL.map(Orthogonal::try_from), Orthogonal::ALL.map(Ok));
+        assert_eq!(Orthogonal::try_from(NorthWest), Err(NorthWest));
+        assert!(Orthogonal::ALL.iter().all(|d| Direction::from(d.opposite()) == Direction::from(*d).opposite()));
+        assert_eq!(Orthogonal::try_from('v'), Ok(Orthogonal::South));
+        assert_eq!("0123".chars().map(Orthogonal::from_digit).collect::<Vec<_>>(), [Orthogonal::East, Orthogonal::South, Orthogonal::West, Orthogonal::North].map(Some));
+        assert_eq!(Orthogonal::from_digit('4'), None);
     }
 }
\ No newline at end of file
@@ -0,0 +1,172 @@
+/// The greatest common divisor, always non-negative, `gcd(0, 0)` is 0. `None` when it is 2^127, which only happens
+/// with `i128::MIN` as an argument.
+pub fn checked_gcd(first: i128, second: i128) -> Option<i128> {
+    let (mut a, mut b) = (first.unsigned_abs(), second.unsigned_abs());
+
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    i128::try_from(a).ok()
+}
+
+pub fn gcd(first: i128, second: i128) -> i128 {
+    checked_gcd(first, second).expect("gcd overflows i128")
+}
+
+/// The least common multiple, always non-negative, 0 when an argument is 0. `None` when it overflows.
+pub fn checked_lcm(first: i128, second: i128) -> Option<i128> {
+    if first == 0 || second == 0 {
+        return Some(0);
+    }
+
+    (first / checked_gcd(first, second)?).checked_mul(second)?.checked_abs()
+}
+
+pub fn lcm(first: i128, second: i128) -> i128 {
+    checked_lcm(first, second).expect("lcm overflows i128")
+}
+
+/// The gcd of all numbers, 0 for none.
+pub fn gcd_all(numbers: impl IntoIterator<Item = i128>) -> Option<i128> {
+    numbers.into_iter().try_fold(0, checked_gcd)
+}
+
+/// The lcm of all numbers, 1 for none.
+pub fn lcm_all(numbers: impl IntoIterator<Item = i128>) -> Option<i128> {
+    numbers.into_iter().try_fold(1, checked_lcm)
+}
+
+/// Returns `(g, x, y)` with `g` the gcd and `first * x + second * y == g`.
+pub fn extended_gcd(first: i128, second: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (first, second);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < 0 { (-old_r, -old_x, -old_y) } else { (old_r, old_x, old_y) }
+}
+
+/// `first * second` modulo a positive modulus, without overflowing.
+pub fn mod_mul(first: i128, second: i128, modulus: i128) -> i128 {
+    let (a, b) = (first.rem_euclid(modulus), second.rem_euclid(modulus));
+
+    if let Some(product) = a.checked_mul(b) {
+        return product % modulus;
+    }
+
+    // Double and add, both terms stay below the modulus so their sum fits in an u128
+    let (mut a, mut b, modulus) = (a as u128, b as u128, modulus as u128);
+    let mut result = 0;
+
+    while b > 0 {
+        if b & 1 == 1 {
+            result = (result + a) % modulus;
+        }
+
+        a = (a * 2) % modulus;
+        b >>= 1;
+    }
+
+    result as i128
+}
+
+/// `base` to the power `exponent` modulo a positive modulus.
+pub fn mod_pow(base: i128, mut exponent: u128, modulus: i128) -> i128 {
+    let mut base = base.rem_euclid(modulus);
+    let mut result = 1 % modulus;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mod_mul(result, base, modulus);
+        }
+
+        base = mod_mul(base, base, modulus);
+        exponent >>= 1;
+    }
+
+    result
+}
+
+/// The `x` in `0..modulus` with `value * x ≡ 1`, `None` when `value` and the modulus are not coprime.
+pub fn mod_inverse(value: i128, modulus: i128) -> Option<i128> {
+    let (g, x, _) = extended_gcd(value.rem_euclid(modulus), modulus);
+
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// Chinese remainder theorem for `(residue, modulus)` congruences with positive moduli that do not need to be
+/// coprime. Returns the smallest non-negative solution with the lcm of the moduli, `None` when the congruences
+/// contradict each other or the lcm overflows.
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    congruences.into_iter().try_fold((0, 1), |(residue, modulus), (other_residue, other_modulus)| {
+        let (g, inverse, _) = extended_gcd(modulus, other_modulus);
+        let difference = other_residue - residue;
+
+        if difference % g != 0 {
+            return None;
+        }
+
+        // residue + modulus * k solves both when k ≡ difference / g * inverse (mod other_modulus / g)
+        let step = other_modulus / g;
+        let k = mod_mul(difference / g, inverse, step);
+        let combined_modulus = modulus.checked_mul(step)?;
+
+        Some(((residue + mod_mul(modulus, k, combined_modulus)).rem_euclid(combined_modulus), combined_modulus))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!((gcd(0, 7), gcd(7, 0), gcd(0, 0)), (7, 7, 0));
+        assert_eq!(checked_gcd(i128::MIN, 0), None);
+
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(checked_lcm(i128::MAX, 2), None);
+        assert_eq!(checked_lcm(1 << 100, 1 << 101), Some(1 << 101));
+
+        assert_eq!(gcd_all([24, 36, 60]), Some(12));
+        assert_eq!(lcm_all([2, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_all([1 << 70, 3 << 60]), Some(3 << 70));
+        assert_eq!(lcm_all([i128::MAX, i128::MAX - 1]), None);
+    }
+
+    #[test]
+    fn test_modular_arithmetic() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!((g, 240 * x + 46 * y), (2, 2));
+
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(4, 10), None);
+
+        assert_eq!(mod_pow(2, 10, 1000), 24);
+        assert_eq!(mod_pow(5, 0, 1), 0);
+
+        let prime = (1 << 89) - 1;
+        assert_eq!(mod_pow(3, prime as u128 - 1, prime), 1);
+        assert_eq!(mod_mul(prime - 1, prime - 1, prime), 1);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(3, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt([]), Some((0, 1)));
+    }
+}
@@ -0,0 +1,193 @@
+use std::fmt::{Display, Formatter};
+use std::str::{FromStr, Lines};
+use std::fmt;
+use crate::grid::Grid;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    /// Set by the runner, the parser itself does not know which day it belongs to.
+    pub day: Option<u8>,
+    /// 1-based line number in the raw input
+    pub line: usize,
+    /// 1-based column (in characters) in the raw input
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn with_day(self, day: u8) -> Self {
+        Self { day: Some(day), ..self }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day}, ")?;
+        }
+
+        write!(f, "line {}, column {}: expected {}", self.line, self.column, self.expected)?;
+
+        match self.found.as_str() {
+            "" => write!(f, ", found nothing"),
+            found => write!(f, ", found `{found}`"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Helpers for parsing a raw puzzle input. All fragments passed to it must be slices of the raw input, so the position
+/// of a fragment that fails to parse can be reported as a line and column.
+#[derive(Clone, Copy)]
+pub struct Parser<'a> {
+    raw_input: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(raw_input: &'a str) -> Self {
+        Self { raw_input }
+    }
+
+    pub fn lines(&self) -> Lines<'a> {
+        self.raw_input.lines()
+    }
+
+    /// An empty fragment at the end of the input, to report missing lines or sections.
+    pub fn end(&self) -> &'a str {
+        &self.raw_input[self.raw_input.len()..]
+    }
+
+    pub fn error(&self, fragment: &str, expected: impl Into<String>) -> ParseError {
+        let offset = (fragment.as_ptr() as usize).wrapping_sub(self.raw_input.as_ptr() as usize);
+        debug_assert!(offset <= self.raw_input.len(), "fragment is not part of the raw input");
+
+        let before = &self.raw_input[..offset.min(self.raw_input.len())];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        ParseError {
+            day: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.into(),
+            found: fragment.lines().next().unwrap_or("").to_string(),
+        }
+    }
+
+    pub fn number<T: FromStr>(&self, fragment: &'a str) -> Result<T, ParseError> {
+        fragment
+            .parse::<T>()
+            .map_err(|_| self.error(fragment, "a number"))
+    }
+
+    /// Numbers separated by any amount of whitespace.
+    pub fn numbers<T: FromStr>(&self, fragment: &'a str) -> Result<Vec<T>, ParseError> {
+        fragment
+            .split_whitespace()
+            .map(|n| self.number(n))
+            .collect()
+    }
+
+    pub fn split_once(&self, fragment: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+        fragment
+            .split_once(delimiter)
+            .ok_or_else(|| self.error(fragment, format!("`{delimiter}`")))
+    }
+
+    /// Strips a prefix and suffix, like the brackets around a list.
+    pub fn strip<'b>(&self, fragment: &'a str, prefix: &'b str, suffix: &'b str) -> Result<&'a str, ParseError> {
+        fragment
+            .strip_prefix(prefix)
+            .ok_or_else(|| self.error(fragment, format!("`{prefix}`")))?
+            .strip_suffix(suffix)
+            .ok_or_else(|| self.error(&fragment[fragment.len() - suffix.len().min(fragment.len())..], format!("`{suffix}`")))
+    }
+
+    /// Maps every character of a rectangular block of lines, `expected` describes the characters `f` accepts. The
+    /// block must not be empty.
+    pub fn grid<T>(&self, fragment: &'a str, expected: &str, f: impl Fn(char) -> Option<T>) -> Result<Grid<T>, ParseError> {
+        let mut width = None;
+
+        let rows = fragment
+            .lines()
+            .map(|line| {
+                if *width.get_or_insert(line.len()) != line.len() {
+                    return Err(self.error(line, format!("a row of {} characters", width.unwrap())));
+                }
+
+                line
+                    .char_indices()
+                    .map(|(i, c)| f(c).ok_or_else(|| self.error(&line[i..i + c.len_utf8()], expected)))
+                    .collect()
+            })
+            .collect::<Result<Vec<Vec<T>>, _>>()?;
+
+        if rows.is_empty() || rows[0].is_empty() {
+            return Err(self.error(fragment, "a grid of at least one row and column"));
+        }
+
+        Ok(Grid::from_rows(rows))
+    }
+
+    /// A rectangular block of lines that only contains characters from `allowed`.
+    pub fn char_grid(&self, fragment: &'a str, allowed: &str) -> Result<Grid<char>, ParseError> {
+        let expected = format!("one of `{allowed}`");
+
+        self.grid(fragment, &expected, |c| allowed.contains(c).then_some(c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_location() {
+        let raw_input = "12 34\n56 7x";
+        let parser = Parser::new(raw_input);
+        let line = parser.lines().nth(1).unwrap();
+        let error = parser.number::<i32>(&line[3..]).unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.to_string(), "line 2, column 4: expected a number, found `7x`");
+        assert_eq!(error.with_day(9).to_string(), "day 9, line 2, column 4: expected a number, found `7x`");
+    }
+
+    #[test]
+    fn test_numbers() {
+        let raw_input = "1  2 3\n4 -5";
+        let parser = Parser::new(raw_input);
+
+        assert_eq!(parser.numbers::<u8>(&raw_input[..6]), Ok(vec![1, 2, 3]));
+        assert_eq!(parser.numbers::<u8>(raw_input).unwrap_err().column, 3);
+    }
+
+    #[test]
+    fn test_split_once_and_strip() {
+        let raw_input = "AAA = (BBB, CCC)";
+        let parser = Parser::new(raw_input);
+        let (node, options) = parser.split_once(raw_input, " = ").unwrap();
+
+        assert_eq!(node, "AAA");
+        assert_eq!(parser.strip(options, "(", ")"), Ok("BBB, CCC"));
+        assert_eq!(parser.split_once(node, ", ").unwrap_err().column, 1);
+        assert_eq!(parser.strip(node, "(", ")").unwrap_err().expected, "`(`");
+    }
+
+    #[test]
+    fn test_char_grid() {
+        let raw_input = "#.#\n.O.\n#x#";
+        let parser = Parser::new(raw_input);
+        let error = parser.char_grid(raw_input, ".#O").unwrap_err();
+
+        assert_eq!((error.line, error.column, error.found.as_str()), (3, 2, "x"));
+        assert_eq!(parser.char_grid(&raw_input[..7], ".#O"), Ok(Grid::from_lines("#.#\n.O.")));
+
+        let raw_input = "#.\n#";
+        let parser = Parser::new(raw_input);
+
+        assert_eq!(parser.char_grid(raw_input, ".#").unwrap_err().expected, "a row of 2 characters");
+        assert_eq!(Parser::new("").char_grid("", ".#").unwrap_err().expected, "a grid of at least one row and column");
+    }
+}
@@ -0,0 +1,142 @@
+use std::fmt::{Display, Formatter};
+use std::num::TryFromIntError;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::fmt;
+use crate::{Coordinate, Direction};
+
+/// A position on an unbounded plane. Like `Coordinate`, `y` grows towards the south and `x` towards the east.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub y: i64,
+    pub x: i64,
+}
+
+/// The difference between two points, or a step to add to one.
+pub type Vector = Point;
+
+impl Point {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    pub const fn new(y: i64, x: i64) -> Self {
+        Self { y, x }
+    }
+
+    pub fn manhattan_length(self) -> i64 {
+        self.y.abs() + self.x.abs()
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> i64 {
+        (self - other).manhattan_length()
+    }
+
+    pub fn step(self, direction: Direction, distance: i64) -> Self {
+        self + direction.unit_vector() * distance
+    }
+
+    /// The 4 orthogonally adjacent points, with the direction to reach them.
+    pub fn neighbors(self) -> [(Direction, Self); 4] {
+        Direction::ORTHOGONAL.map(|direction| (direction, self.step(direction, 1)))
+    }
+
+    /// The coordinate on a grid that repeats infinitely in every direction.
+    pub fn wrap(self, height: usize, width: usize) -> Coordinate {
+        (self.y.rem_euclid(height as i64) as usize, self.x.rem_euclid(width as i64) as usize)
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.y + other.y, self.x + other.x)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self::new(self.y - other.y, self.x - other.x)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Self;
+
+    fn mul(self, factor: i64) -> Self::Output {
+        Self::new(self.y * factor, self.x * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.y, -self.x)
+    }
+}
+
+impl From<Coordinate> for Point {
+    fn from((y, x): Coordinate) -> Self {
+        Self::new(y as i64, x as i64)
+    }
+}
+
+/// Fails for points with a negative `y` or `x`.
+impl TryFrom<Point> for Coordinate {
+    type Error = TryFromIntError;
+
+    fn try_from(point: Point) -> Result<Self, Self::Error> {
+        Ok((usize::try_from(point.y)?, usize::try_from(point.x)?))
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.y, self.x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Direction::{North, South, West};
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point::new(2, -3);
+        let b = Point::new(-1, 4);
+
+        assert_eq!(a + b, Point::new(1, 1));
+        assert_eq!(a - b, Point::new(3, -7));
+        assert_eq!(a * 3, Point::new(6, -9));
+        assert_eq!(-a, Point::new(-2, 3));
+        assert_eq!(a.manhattan_distance(b), 10);
+    }
+
+    #[test]
+    fn test_steps() {
+        assert_eq!(Point::ORIGIN.step(North, 5), Point::new(-5, 0));
+        assert_eq!(Point::ORIGIN.step(West, 2).step(South, 1), Point::new(1, -2));
+        assert_eq!(Point::new(-1, 11).wrap(10, 10), (9, 1));
+    }
+
+    #[test]
+    fn test_coordinate_conversion() {
+        assert_eq!(Point::from((3, 4)), Point::new(3, 4));
+        assert_eq!(Coordinate::try_from(Point::new(3, 4)), Ok((3, 4)));
+        assert!(Coordinate::try_from(Point::new(-1, 4)).is_err());
+    }
+}
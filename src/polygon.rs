@@ -0,0 +1,129 @@
+use crate::Direction;
+use crate::number_theory::gcd;
+use crate::point::Point;
+
+/// A closed polygon with its corners on integer points, the last vertex connects back to the first.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point>) -> Self {
+        Self { vertices }
+    }
+
+    /// Walks the steps from `start`, a vertex is added after every step. The walk should end back at `start`.
+    pub fn from_steps(start: Point, steps: impl IntoIterator<Item = (Direction, i64)>) -> Self {
+        let vertices = steps
+            .into_iter()
+            .scan(start, |position, (direction, length)| {
+                *position = position.step(direction, length);
+                Some(*position)
+            })
+            .collect();
+
+        Self { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        self.vertices.iter().copied().zip(self.vertices.iter().copied().cycle().skip(1))
+    }
+
+    /// Twice the area by the shoelace formula, which is always an integer. Positive when the vertices go clockwise as
+    /// drawn with `y` growing towards the south, negative when they go counterclockwise.
+    pub fn twice_signed_area(&self) -> i64 {
+        self.edges().map(|(a, b)| a.x * b.y - b.x * a.y).sum()
+    }
+
+    pub fn area(&self) -> f64 {
+        self.twice_signed_area().abs() as f64 / 2.0
+    }
+
+    /// The length of the edges, straight lines between the vertices.
+    pub fn perimeter(&self) -> f64 {
+        self.edges().map(|(a, b)| ((a.x - b.x) as f64).hypot((a.y - b.y) as f64)).sum()
+    }
+
+    /// The number of integer points on the edges. For horizontal and vertical edges this is the perimeter.
+    pub fn boundary_points(&self) -> i64 {
+        self.edges().map(|(a, b)| gcd((a.x - b.x) as i128, (a.y - b.y) as i128) as i64).sum()
+    }
+
+    /// The number of integer points strictly inside, by Pick's theorem.
+    pub fn interior_points(&self) -> i64 {
+        (self.twice_signed_area().abs() - self.boundary_points() + 2) / 2
+    }
+
+    /// Whether the point is strictly inside, points on the edges are not.
+    pub fn contains(&self, point: Point) -> bool {
+        let on_edge = |a: Point, b: Point| {
+            let (edge, offset) = (b - a, point - a);
+
+            edge.x * offset.y == edge.y * offset.x
+                && (a.x.min(b.x)..=a.x.max(b.x)).contains(&point.x)
+                && (a.y.min(b.y)..=a.y.max(b.y)).contains(&point.y)
+        };
+
+        if self.edges().any(|(a, b)| on_edge(a, b)) {
+            return false;
+        }
+
+        // A ray towards the east crosses the edges an odd number of times from inside. The edge crosses the row of the
+        // point at `x = a.x + (point.y - a.y) * (b.x - a.x) / (b.y - a.y)`, compared without dividing.
+        self.edges()
+            .filter(|&(a, b)| (a.y > point.y) != (b.y > point.y))
+            .filter(|&(a, b)| {
+                let height = b.y - a.y;
+                ((a.x - point.x) * height + (point.y - a.y) * (b.x - a.x) > 0) == (height > 0)
+            })
+            .count() % 2 == 1
+    }
+
+    /// The number of integer points inside or on the edges.
+    pub fn enclosed_points(&self) -> i64 {
+        self.interior_points() + self.boundary_points()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Direction::{East, North, South, West};
+
+    #[test]
+    fn test_rectangle() {
+        let rectangle = Polygon::from_steps(Point::ORIGIN, [(East, 4), (South, 3), (West, 4), (North, 3)]);
+
+        assert_eq!(rectangle.vertices().last(), Some(&Point::ORIGIN));
+        assert_eq!(rectangle.twice_signed_area(), 24);
+        assert_eq!(rectangle.perimeter(), 14.0);
+        assert_eq!((rectangle.boundary_points(), rectangle.interior_points(), rectangle.enclosed_points()), (14, 6, 20));
+    }
+
+    #[test]
+    fn test_triangle() {
+        let triangle = Polygon::new(vec![Point::new(0, 0), Point::new(0, 4), Point::new(3, 0)]);
+
+        assert_eq!(triangle.twice_signed_area(), 12);
+        assert_eq!(Polygon::new(triangle.vertices().iter().rev().copied().collect()).twice_signed_area(), -12);
+        assert_eq!(triangle.area(), 6.0);
+        assert_eq!(triangle.perimeter(), 12.0);
+        assert_eq!((triangle.boundary_points(), triangle.interior_points()), (8, 3));
+    }
+
+    #[test]
+    fn test_contains() {
+        let shape = Polygon::new(vec![Point::new(0, 0), Point::new(0, 6), Point::new(4, 6), Point::new(4, 3), Point::new(2, 3), Point::new(2, 0)]);
+        let contained = (-1..=7).flat_map(|y| (-1..=7).map(move |x| Point::new(y, x))).filter(|&p| shape.contains(p)).count();
+
+        assert_eq!(contained as i64, shape.interior_points());
+        assert!(shape.contains(Point::new(1, 1)) && shape.contains(Point::new(3, 5)));
+        assert!(!shape.contains(Point::new(3, 1)) && !shape.contains(Point::new(2, 2)) && !shape.contains(Point::new(0, 6)));
+        assert!(Polygon::new(vec![Point::new(0, 0), Point::new(0, 4), Point::new(3, 0)]).contains(Point::new(1, 1)));
+    }
+}
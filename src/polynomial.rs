@@ -0,0 +1,187 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Mul, Neg, Sub};
+use std::fmt;
+use crate::number_theory::gcd;
+
+/// The polynomial through samples at consecutive integers, stored as the leading diagonal of its finite difference
+/// table. A polynomial through integer samples at integers has integer values at every integer, so evaluating is exact.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DifferenceTable {
+    start: i128,
+    differences: Vec<i128>,
+}
+
+impl DifferenceTable {
+    /// The polynomial of the lowest degree with `p(start + i) == samples[i]`.
+    pub fn new(start: i128, samples: &[i128]) -> Self {
+        let mut row = samples.to_vec();
+        let mut differences = Vec::new();
+
+        while !row.is_empty() && row.iter().any(|&v| v != 0) {
+            differences.push(row[0]);
+            row = row.windows(2).map(|w| w[1] - w[0]).collect();
+        }
+
+        Self { start, differences }
+    }
+
+    /// The degree of the polynomial, `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.differences.len().checked_sub(1)
+    }
+
+    /// The value at any integer, before, between or after the samples. Uses Newton's forward formula
+    /// `p(start + t) = Σ Δᵏ · C(t, k)`.
+    pub fn evaluate(&self, x: i128) -> i128 {
+        let t = x - self.start;
+        let mut binomial = 1;
+        let mut value = 0;
+
+        for (k, difference) in (0..).zip(&self.differences) {
+            value += difference * binomial;
+            // C(t, k + 1) = C(t, k) · (t − k) / (k + 1), which divides exactly
+            binomial = binomial * (t - k) / (k + 1);
+        }
+
+        value
+    }
+}
+
+/// An exact fraction, kept with a positive denominator and no common factors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    pub const ZERO: Self = Self { numerator: 0, denominator: 1 };
+
+    /// Panics when the denominator is 0.
+    pub fn new(numerator: i128, denominator: i128) -> Self {
+        assert_ne!(denominator, 0, "denominator of a rational is 0");
+
+        let divisor = gcd(numerator, denominator) * denominator.signum();
+        Self { numerator: numerator / divisor, denominator: denominator / divisor }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    /// `None` when the value is not a whole number.
+    pub fn to_integer(&self) -> Option<i128> {
+        (self.denominator == 1).then_some(self.numerator)
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(value: i128) -> Self {
+        Self { numerator: value, denominator: 1 }
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.numerator * other.denominator + other.numerator * self.denominator, self.denominator * other.denominator)
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        self + -other
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        Self::new(self.numerator * other.numerator, self.denominator * other.denominator)
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self { numerator: -self.numerator, denominator: self.denominator }
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.denominator {
+            1 => write!(f, "{}", self.numerator),
+            _ => write!(f, "{}/{}", self.numerator, self.denominator),
+        }
+    }
+}
+
+/// The value at `x` of the polynomial of the lowest degree through the `(x, y)` samples, in Lagrange form. The
+/// samples can be at any distinct integers. Panics when two samples have the same `x`.
+pub fn lagrange(samples: &[(i128, i128)], x: i128) -> Rational {
+    samples
+        .iter()
+        .enumerate()
+        .map(|(i, &(xi, yi))| {
+            samples
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .fold(Rational::from(yi), |term, (_, &(xj, _))| term * Rational::new(x - xj, xi - xj))
+        })
+        .fold(Rational::ZERO, Add::add)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_difference_table() {
+        let table = DifferenceTable::new(0, &[10, 13, 16, 21, 30, 45]);
+
+        assert_eq!(table.degree(), Some(3));
+        assert_eq!(table.evaluate(6), 68);
+        assert_eq!(table.evaluate(-1), 5);
+        assert_eq!(table.evaluate(2), 16);
+
+        // n² sampled at 5, 6 and 7
+        let squares = DifferenceTable::new(5, &[25, 36, 49]);
+        assert_eq!((squares.evaluate(-3), squares.evaluate(1_000_000)), (9, 1_000_000_000_000));
+        assert_eq!(DifferenceTable::new(0, &[0, 0]).degree(), None);
+        assert_eq!(DifferenceTable::new(0, &[]).evaluate(3), 0);
+    }
+
+    #[test]
+    fn test_rational() {
+        let half = Rational::new(2, 4);
+
+        assert_eq!((half.numerator(), half.denominator()), (1, 2));
+        assert_eq!(Rational::new(3, -6), -half);
+        assert_eq!((half + Rational::new(1, 3)).to_string(), "5/6");
+        assert_eq!((half - half).to_integer(), Some(0));
+        assert_eq!((half * Rational::from(4)).to_integer(), Some(2));
+        assert_eq!(half.to_integer(), None);
+    }
+
+    #[test]
+    fn test_lagrange() {
+        // 3x² − 2x + 1
+        let samples = [(-2, 17), (1, 2), (4, 41)];
+
+        assert_eq!(lagrange(&samples, 10), Rational::from(281));
+        assert_eq!(lagrange(&samples, 0), Rational::from(1));
+        // The line through (0, 0) and (2, 1)
+        assert_eq!(lagrange(&[(0, 0), (2, 1)], 1), Rational::new(1, 2));
+    }
+}
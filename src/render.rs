@@ -0,0 +1,188 @@
+use std::fmt::Write;
+use crate::{Coordinate, Direction};
+use crate::Direction::{East, North, NorthEast, NorthWest, South, SouthEast, SouthWest, West};
+use crate::grid::Grid;
+use crate::image::{Image, Rgb};
+
+/// What a grid cell looks like: a character for text output and a color for terminals and images.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cell {
+    pub glyph: char,
+    pub color: Rgb,
+}
+
+impl Cell {
+    pub const fn new(glyph: char, color: Rgb) -> Self {
+        Self { glyph, color }
+    }
+}
+
+/// A grid drawn as cells, with overlays for paths and highlighted cells and a legend of the colors.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Canvas {
+    cells: Grid<Cell>,
+    legend: Vec<(Rgb, String)>,
+}
+
+impl Canvas {
+    /// Every cell the same, to draw on with `with_cells`.
+    pub fn new(height: usize, width: usize, background: Cell) -> Self {
+        Self { cells: Grid::new(height, width, background), legend: Vec::new() }
+    }
+
+    pub fn from_grid<T>(grid: &Grid<T>, style: impl FnMut(&T) -> Cell) -> Self {
+        Self { cells: grid.map(style), legend: Vec::new() }
+    }
+
+    pub fn cells(&self) -> &Grid<Cell> {
+        &self.cells
+    }
+
+    pub fn legend(&self) -> &[(Rgb, String)] {
+        &self.legend
+    }
+
+    /// Recolors the cells, their glyphs are kept.
+    pub fn with_highlight(mut self, coordinates: impl IntoIterator<Item = Coordinate>, color: Rgb) -> Self {
+        for coordinate in coordinates {
+            self.cells[coordinate].color = color;
+        }
+
+        self
+    }
+
+    /// Replaces the cells, glyph and color.
+    pub fn with_cells(mut self, coordinates: impl IntoIterator<Item = Coordinate>, cell: Cell) -> Self {
+        for coordinate in coordinates {
+            self.cells[coordinate] = cell;
+        }
+
+        self
+    }
+
+    /// Draws arrows along straight lines from each corner to the next, corners must be in a line horizontally,
+    /// vertically or diagonally. Consecutive cells of a path are valid corners too.
+    pub fn with_path(mut self, corners: &[Coordinate], color: Rgb) -> Self {
+        for segment in corners.windows(2) {
+            let direction = Direction::from_coordinates(&segment[0], &segment[1])
+                .expect("the corners of a path are in a straight line");
+            let mut coordinate = segment[0];
+
+            while coordinate != segment[1] {
+                self.cells[coordinate] = Cell::new(arrow(direction), color);
+                coordinate = self.cells
+                    .coordinate_in_direction(coordinate, direction)
+                    .expect("a path stays inside the grid");
+            }
+        }
+
+        if let Some(&end) = corners.last() {
+            self.cells[end].color = color;
+        }
+
+        self
+    }
+
+    pub fn with_legend(mut self, color: Rgb, label: &str) -> Self {
+        self.legend.push((color, String::from(label)));
+        self
+    }
+
+    /// Only the glyphs, the legend is left out since it is about colors.
+    pub fn to_text(&self) -> String {
+        self.cells.map(|cell| cell.glyph).to_string()
+    }
+
+    /// Glyphs in 24 bit terminal colors, followed by the legend.
+    pub fn to_ansi(&self) -> String {
+        let mut ansi = String::new();
+
+        for row in self.cells.rows() {
+            for cell in row {
+                write!(ansi, "{}{}", foreground(cell.color), cell.glyph).unwrap();
+            }
+
+            ansi.push_str("\x1b[0m\n");
+        }
+
+        for (color, label) in &self.legend {
+            writeln!(ansi, "{}\u{2588}\u{2588}\x1b[0m {label}", foreground(*color)).unwrap();
+        }
+
+        ansi
+    }
+
+    /// Every cell as a square of `scale` by `scale` pixels in its color. Images have no legend, there is no text to
+    /// label the colors with.
+    pub fn to_image(&self, scale: usize) -> Image {
+        let mut image = Image::new(self.cells.height() * scale, self.cells.width() * scale, Rgb::BLACK);
+
+        for ((y, x), cell) in self.cells.iter() {
+            image.fill_square(y * scale, x * scale, scale, cell.color);
+        }
+
+        image
+    }
+}
+
+fn arrow(direction: Direction) -> char {
+    match direction {
+        North => '^',
+        East => '>',
+        South => 'v',
+        West => '<',
+        NorthEast | SouthWest => '/',
+        SouthEast | NorthWest => '\\',
+    }
+}
+
+fn foreground(color: Rgb) -> String {
+    format!("\x1b[38;2;{};{};{}m", color.r, color.g, color.b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Canvas {
+        let grid = Grid::from_lines("#...\n....\n...#");
+
+        Canvas::from_grid(&grid, |&c| Cell::new(c, if c == '#' { Rgb::DARK_GREEN } else { Rgb::WHITE }))
+    }
+
+    #[test]
+    fn test_text() {
+        let canvas = example()
+            .with_path(&[(0, 1), (0, 3), (1, 3)], Rgb::RED)
+            .with_path(&[(2, 0), (1, 1)], Rgb::BLUE);
+
+        assert_eq!(canvas.to_text(), "#>>v\n....\n/..#");
+        assert_eq!(canvas.cells()[(1, 3)], Cell::new('.', Rgb::RED));
+        assert_eq!(canvas.cells()[(1, 1)], Cell::new('.', Rgb::BLUE));
+
+        let drawn = Canvas::new(2, 2, Cell::new('.', Rgb::BLACK)).with_cells([(0, 1), (1, 1)], Cell::new('O', Rgb::RED));
+        assert_eq!(drawn.to_text(), ".O\n.O");
+        assert_eq!(drawn.cells()[(1, 1)].color, Rgb::RED);
+    }
+
+    #[test]
+    fn test_ansi() {
+        let canvas = example().with_highlight([(1, 0)], Rgb::YELLOW).with_legend(Rgb::YELLOW, "energized");
+        let ansi = canvas.to_ansi();
+
+        assert!(ansi.starts_with("\x1b[38;2;20;90;40m#\x1b[38;2;255;255;255m."));
+        assert!(ansi.contains("\x1b[38;2;240;200;40m."));
+        assert!(ansi.ends_with("\x1b[38;2;240;200;40m\u{2588}\u{2588}\x1b[0m energized\n"));
+        assert_eq!(ansi.lines().count(), 4);
+    }
+
+    #[test]
+    fn test_image() {
+        let image = example().to_image(2);
+
+        assert_eq!((image.height(), image.width()), (6, 8));
+        assert_eq!(image.get(1, 1), Rgb::DARK_GREEN);
+        assert_eq!(image.get(1, 2), Rgb::WHITE);
+        assert_eq!(image.get(5, 7), Rgb::DARK_GREEN);
+    }
+}
@@ -0,0 +1,69 @@
+use std::time::Duration;
+use serde::Serialize;
+use crate::solution::Part;
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SolutionResult {
+    pub day: u8,
+    pub part: Part,
+    /// `None` for parts that do not exist
+    pub answer: Option<String>,
+    pub input: String,
+    /// Parsing and solving, in milliseconds with microsecond precision
+    pub elapsed_ms: f64,
+}
+
+impl SolutionResult {
+    pub fn new(day: u8, part: Part, answer: Option<String>, input: &str, elapsed: Duration) -> Self {
+        Self { day, part, answer, input: input.to_string(), elapsed_ms: elapsed.as_micros() as f64 / 1000.0 }
+    }
+}
+
+pub const CSV_HEADER: &str = "day,part,answer,input,elapsed_ms";
+
+pub fn csv_row(result: &SolutionResult) -> String {
+    format!(
+        "{},{},{},{},{}",
+        result.day,
+        result.part,
+        csv_field(result.answer.as_deref().unwrap_or("")),
+        csv_field(&result.input),
+        result.elapsed_ms,
+    )
+}
+
+/// Quotes a field when it contains a separator, quote or newline.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub fn json(results: &[SolutionResult]) -> String {
+    serde_json::to_string_pretty(results).expect("results are always serializable")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_row() {
+        let result = SolutionResult::new(6, 1, Some(String::from("288")), "data/day6/input,1.txt", Duration::from_micros(1500));
+
+        assert_eq!(csv_row(&result), "6,1,288,\"data/day6/input,1.txt\",1.5");
+        assert_eq!(csv_row(&SolutionResult { answer: None, ..result }), "6,1,,\"data/day6/input,1.txt\",1.5");
+    }
+
+    #[test]
+    fn test_json() {
+        let results = [SolutionResult::new(25, 2, None, "stdin", Duration::from_millis(2))];
+
+        assert_eq!(
+            json(&results),
+            "[\n  {\n    \"day\": 25,\n    \"part\": 2,\n    \"answer\": null,\n    \"input\": \"stdin\",\n    \"elapsed_ms\": 2.0\n  }\n]",
+        );
+    }
+}
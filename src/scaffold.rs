@@ -0,0 +1,154 @@
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::{fmt, fs, io};
+
+/// The solution of every new day starts as a copy of day 0, including its placeholder tests.
+const TEMPLATE: &str = include_str!("days/day0.rs");
+
+/// Data files that are created empty, to be filled with the puzzle input and the example from the puzzle text.
+const DATA_FILES: [&str; 2] = ["input.txt", "input_example.txt"];
+
+/// Turns the day 0 template into the solution module of another day.
+pub fn render_template(day: u8) -> String {
+    TEMPLATE
+        .replace("Day0", &format!("Day{day}"))
+        .replace("day0", &format!("day{day}"))
+        .replace("const DAY: u8 = 0;", &format!("const DAY: u8 = {day};"))
+}
+
+/// Adds the module declaration and the runner registration of a day to `src/days/mod.rs`, both in day order.
+pub fn register_day(days_mod: &str, day: u8) -> Result<String, ScaffoldError> {
+    let declaration = format!("pub mod day{day};");
+    let registration = format!("    Day::of::<day{day}::Day{day}>(),");
+
+    if days_mod.lines().any(|l| l == declaration || l == registration) {
+        return Err(ScaffoldError::AlreadyRegistered(day));
+    }
+
+    let mut lines: Vec<&str> = days_mod.lines().collect();
+
+    let day_of = |l: &str, prefix: &str| l.strip_prefix(prefix).and_then(|rest| {
+        rest.split(|c: char| !c.is_ascii_digit()).next()?.parse::<u8>().ok()
+    });
+
+    let Some(last_declaration) = lines.iter().rposition(|l| day_of(l, "pub mod day").is_some_and(|d| d < day)) else {
+        return Err(ScaffoldError::Registration(format!("no module declarations before day {day} were found")));
+    };
+    lines.insert(last_declaration + 1, &declaration);
+
+    let Some(days_start) = lines.iter().position(|l| l.starts_with("pub static DAYS")) else {
+        return Err(ScaffoldError::Registration(String::from("the `DAYS` registry was not found")));
+    };
+    let Some(days_end) = lines[days_start..].iter().position(|l| *l == "];").map(|i| days_start + i) else {
+        return Err(ScaffoldError::Registration(String::from("the end of the `DAYS` registry was not found")));
+    };
+
+    let position = (days_start + 1..days_end)
+        .find(|&i| day_of(lines[i], "    Day::of::<day").is_some_and(|d| d > day))
+        .unwrap_or(days_end);
+    lines.insert(position, &registration);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Creates the solution module and the data directory of a day and registers it with the runner. Existing data
+/// files are kept, an existing solution module is never overwritten.
+pub fn scaffold(root: &Path, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let module_path = root.join("src").join("days").join(format!("day{day}.rs"));
+    let days_mod_path = root.join("src").join("days").join("mod.rs");
+    let data_dir = root.join("data").join(format!("day{day}"));
+
+    if module_path.exists() {
+        return Err(ScaffoldError::Exists(module_path));
+    }
+
+    let days_mod = fs::read_to_string(&days_mod_path).map_err(|error| ScaffoldError::Io { path: days_mod_path.clone(), error })?;
+    let days_mod = register_day(&days_mod, day)?;
+
+    let io_error = |path: &Path| {
+        let path = path.to_path_buf();
+        move |error| ScaffoldError::Io { path, error }
+    };
+
+    fs::write(&module_path, render_template(day)).map_err(io_error(&module_path))?;
+    fs::write(&days_mod_path, days_mod).map_err(io_error(&days_mod_path))?;
+    fs::create_dir_all(&data_dir).map_err(io_error(&data_dir))?;
+
+    let mut created = vec![module_path];
+
+    for file in DATA_FILES {
+        let path = data_dir.join(file);
+
+        if !path.exists() {
+            fs::write(&path, "").map_err(io_error(&path))?;
+            created.push(path);
+        }
+    }
+
+    Ok(created)
+}
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    Exists(PathBuf),
+    AlreadyRegistered(u8),
+    Registration(String),
+    Io { path: PathBuf, error: io::Error },
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::Exists(path) => write!(f, "`{}` already exists", path.display()),
+            ScaffoldError::AlreadyRegistered(day) => write!(f, "day {day} is already registered with the runner"),
+            ScaffoldError::Registration(message) => write!(f, "could not register the day: {message}"),
+            ScaffoldError::Io { path, error } => write!(f, "could not write `{}`: {error}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_template() {
+        let module = render_template(26);
+
+        assert!(module.contains("pub struct Day26;"));
+        assert!(module.contains("const DAY: u8 = 26;"));
+        assert!(module.contains("include_str!(\"../../data/day26/input_example.txt\")"));
+        assert!(!module.contains("day0") && !module.contains("Day0"));
+    }
+
+    #[test]
+    fn test_register_day() {
+        let days_mod = "pub mod day0;\npub mod day1;\npub mod day3;\n\npub static DAYS: &[Day] = &[\n    Day::of::<day1::Day1>(),\n    Day::of::<day3::Day3>(),\n];\n";
+
+        assert_eq!(
+            register_day(days_mod, 2).unwrap(),
+            "pub mod day0;\npub mod day1;\npub mod day2;\npub mod day3;\n\npub static DAYS: &[Day] = &[\n    Day::of::<day1::Day1>(),\n    Day::of::<day2::Day2>(),\n    Day::of::<day3::Day3>(),\n];\n",
+        );
+        assert!(register_day(days_mod, 4).unwrap().ends_with("    Day::of::<day3::Day3>(),\n    Day::of::<day4::Day4>(),\n];\n"));
+        assert!(matches!(register_day(days_mod, 3), Err(ScaffoldError::AlreadyRegistered(3))));
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        fs::write(root.join("src/days/mod.rs"), include_str!("days/mod.rs").replace("pub mod day25;\n", "").replace("    Day::of::<day25::Day25>(),\n", "")).unwrap();
+
+        let created = scaffold(&root, 25).unwrap();
+
+        assert_eq!(created.len(), 3);
+        assert_eq!(fs::read_to_string(root.join("src/days/day25.rs")).unwrap(), render_template(25));
+        assert_eq!(fs::read_to_string(root.join("data/day25/input.txt")).unwrap(), "");
+        assert!(matches!(scaffold(&root, 25), Err(ScaffoldError::Exists(_))));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,145 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The cheapest way to reach a goal, the path runs from the start to the goal state, both included.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShortestPath<S, C> {
+    pub cost: C,
+    pub path: Vec<S>,
+}
+
+/// Finds the cheapest path from any of the start states to a state that satisfies `is_goal`. `neighbors` gives the
+/// states reachable from a state with the cost of the step, costs must not be negative and `C::default()` is zero.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<ShortestPath<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    a_star(starts, neighbors, |_| C::default(), is_goal)
+}
+
+/// Like `dijkstra`, but expands the states with the lowest cost plus `heuristic` first. The heuristic must never
+/// overestimate the remaining cost to a goal, otherwise the path found may not be the cheapest.
+pub fn a_star<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<ShortestPath<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    // States are numbered in the order they are found, to keep a single copy of every state
+    let mut indices: HashMap<S, usize> = HashMap::new();
+    let mut states: Vec<S> = Vec::new();
+    let mut costs: Vec<C> = Vec::new();
+    let mut parents: Vec<Option<usize>> = Vec::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        if indices.contains_key(&start) {
+            continue;
+        }
+
+        heap.push(Reverse((heuristic(&start), C::default(), states.len())));
+        indices.insert(start.clone(), states.len());
+        states.push(start);
+        costs.push(C::default());
+        parents.push(None);
+    }
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        if cost > costs[index] {
+            continue;
+        }
+
+        if is_goal(&states[index]) {
+            let mut path = vec![states[index].clone()];
+            let mut current = index;
+
+            while let Some(parent) = parents[current] {
+                path.push(states[parent].clone());
+                current = parent;
+            }
+
+            path.reverse();
+
+            return Some(ShortestPath { cost, path });
+        }
+
+        for (next, step_cost) in neighbors(&states[index]) {
+            let next_cost = cost + step_cost;
+
+            let next_index = match indices.get(&next) {
+                Some(&next_index) if next_cost >= costs[next_index] => continue,
+                Some(&next_index) => {
+                    costs[next_index] = next_cost;
+                    parents[next_index] = Some(index);
+                    next_index
+                },
+                None => {
+                    indices.insert(next.clone(), states.len());
+                    states.push(next);
+                    costs.push(next_cost);
+                    parents.push(Some(index));
+                    states.len() - 1
+                },
+            };
+
+            heap.push(Reverse((next_cost + heuristic(&states[next_index]), next_cost, next_index)));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Coordinate;
+    use crate::grid::Grid;
+
+    #[test]
+    fn test_dijkstra() {
+        let edges = HashMap::from([
+            ('a', vec![('b', 7), ('c', 2)]),
+            ('b', vec![('d', 1)]),
+            ('c', vec![('b', 3), ('d', 8)]),
+            ('d', vec![]),
+        ]);
+
+        let result = dijkstra(['a'], |n| edges[n].clone(), |&n| n == 'd');
+
+        assert_eq!(result, Some(ShortestPath { cost: 6, path: vec!['a', 'c', 'b', 'd'] }));
+        assert_eq!(dijkstra(['d'], |n| edges[n].clone(), |&n| n == 'a'), None);
+    }
+
+    #[test]
+    fn test_a_star() {
+        let maze = Grid::from_lines("..#....\n.##.##.\n....#..\n###...#\n.......");
+        let end: Coordinate = (4, 6);
+
+        let neighbors = |&c: &Coordinate| maze
+            .von_neumann_compass(c)
+            .into_iter()
+            .filter(|&(_, n)| maze[n] == '.')
+            .map(|(_, n)| (n, 1))
+            .collect::<Vec<_>>();
+        let manhattan = |&(y, x): &Coordinate| end.0.abs_diff(y) + end.1.abs_diff(x);
+
+        let result = a_star([(0, 0)], neighbors, manhattan, |&c| c == end).unwrap();
+
+        assert_eq!(result.cost, 10);
+        assert_eq!(result.path.len(), 11);
+        assert_eq!(result.cost, dijkstra([(0, 0)], neighbors, |&c| c == end).unwrap().cost);
+    }
+}
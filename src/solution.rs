@@ -0,0 +1,122 @@
+use std::fmt::{Display, Formatter};
+use std::fmt;
+use crate::animation::Recorder;
+use crate::parse::ParseError;
+use crate::render::Canvas;
+
+pub type Part = u8;
+
+pub const PARTS: [Part; 2] = [1, 2];
+
+/// A day of the puzzle calendar: the raw input is parsed once and shared by both parts.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input<'a>;
+    type Output: Display;
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Output, SolveError>;
+
+    /// Not every day has a second puzzle (day 25), those return `Ok(None)`.
+    fn part2(input: &Self::Input<'_>) -> Result<Option<Self::Output>, SolveError>;
+
+    /// A picture of how a part is solved, for the days that have one.
+    fn render(_input: &Self::Input<'_>, _part: Part) -> Option<Canvas> {
+        None
+    }
+
+    /// A frame of every step of a part, for the days that simulate one.
+    fn animate(_input: &Self::Input<'_>, _part: Part) -> Option<Recorder> {
+        None
+    }
+}
+
+/// A well-formed input that a part has no answer for, like a map without a route to the goal.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SolveError {
+    /// Set by the runner, like the day of a `ParseError`.
+    pub day: Option<u8>,
+    pub part: Option<Part>,
+    pub reason: String,
+}
+
+impl SolveError {
+    pub fn new(reason: impl Into<String>) -> Self {
+        Self { day: None, part: None, reason: reason.into() }
+    }
+
+    pub fn with_part(self, day: u8, part: Part) -> Self {
+        Self { day: Some(day), part: Some(part), ..self }
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let (Some(day), Some(part)) = (self.day, self.part) {
+            write!(f, "day {day} part {part}, ")?;
+        }
+
+        write!(f, "no answer: {}", self.reason)
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// Why the runner could not give the answer to a part.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SolutionError {
+    Parse(ParseError),
+    Solve(SolveError),
+}
+
+impl From<ParseError> for SolutionError {
+    fn from(error: ParseError) -> Self {
+        SolutionError::Parse(error)
+    }
+}
+
+impl From<SolveError> for SolutionError {
+    fn from(error: SolveError) -> Self {
+        SolutionError::Solve(error)
+    }
+}
+
+impl Display for SolutionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SolutionError::Parse(error) => error.fmt(f),
+            SolutionError::Solve(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for SolutionError {}
+
+/// Parses the input and solves a single part, `None` if the part does not exist.
+pub fn solve<S: Solution>(part: Part, raw_input: &str) -> Result<Option<String>, SolutionError> {
+    let input = S::parse(raw_input).map_err(|error| error.with_day(S::DAY))?;
+
+    let answer = match part {
+        1 => S::part1(&input).map(Some),
+        2 => S::part2(&input),
+        _ => Ok(None),
+    };
+
+    Ok(answer.map_err(|error| error.with_part(S::DAY, part))?.map(|answer| answer.to_string()))
+}
+
+/// Parses the input and draws a single part, `None` if the day has no rendering for it.
+pub fn render<S: Solution>(part: Part, raw_input: &str) -> Result<Option<Canvas>, ParseError> {
+    let input = S::parse(raw_input).map_err(|error| error.with_day(S::DAY))?;
+
+    Ok(S::render(&input, part))
+}
+
+/// Parses the input and records the steps of a single part, `None` if the day has no animation for it.
+pub fn animate<S: Solution>(part: Part, raw_input: &str) -> Result<Option<Recorder>, ParseError> {
+    let input = S::parse(raw_input).map_err(|error| error.with_day(S::DAY))?;
+
+    Ok(S::animate(&input, part))
+}